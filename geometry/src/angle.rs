@@ -0,0 +1,199 @@
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::scalar::Real;
+
+/// Angle struct represents a plane angle, stored in radians
+///
+/// Construct with [`Angle::from_radians`] or [`Angle::from_degrees`] so the
+/// unit is always explicit. Plain arithmetic does not wrap, use the
+/// `wrapping_*` methods or [`Angle::normalized`] to bring results into range.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Angle<T = f64> {
+    radians: T,
+}
+
+impl<T: Real> Angle<T> {
+    /// Angle of `radians` radians
+    pub fn from_radians(radians: T) -> Self {
+        Angle { radians }
+    }
+
+    /// Angle of `degrees` degrees
+    pub fn from_degrees(degrees: T) -> Self {
+        Angle::from_radians(degrees * T::pi() / T::from_i32(180))
+    }
+
+    /// The zero angle
+    pub fn zero() -> Self {
+        Angle::from_radians(T::zero())
+    }
+
+    /// Half a turn, pi radians
+    pub fn half_turn() -> Self {
+        Angle::from_radians(T::pi())
+    }
+
+    /// A full turn, two pi radians
+    pub fn full_turn() -> Self {
+        Angle::from_radians(T::pi() * T::from_i32(2))
+    }
+
+    /// Value in radians
+    pub fn radians(&self) -> T {
+        self.radians.clone()
+    }
+
+    /// Value in degrees
+    pub fn degrees(&self) -> T {
+        self.radians() * T::from_i32(180) / T::pi()
+    }
+
+    /// Equivalent angle in `[0, 2pi)`
+    pub fn normalized(&self) -> Self {
+        let full = Self::full_turn().radians;
+        let turns = T::from_f64((self.radians() / full.clone()).to_f64().floor());
+        let r = self.radians() - full.clone() * turns;
+        // Rounding can land exactly on either end of the range
+        if r >= full {
+            Angle::from_radians(r - full)
+        } else if r < T::zero() {
+            Angle::from_radians(r + full)
+        } else {
+            Angle::from_radians(r)
+        }
+    }
+
+    /// Equivalent angle in `(-pi, pi]`
+    pub fn normalized_signed(&self) -> Self {
+        let r = self.normalized();
+        if r.radians > T::pi() {
+            r - Self::full_turn()
+        } else {
+            r
+        }
+    }
+
+    /// Sum wrapped to `(-pi, pi]`
+    pub fn wrapping_add(&self, other: &Self) -> Self {
+        (self.clone() + other.clone()).normalized_signed()
+    }
+
+    /// Difference wrapped to `(-pi, pi]`, the shortest signed turn from `other` to `self`
+    pub fn wrapping_sub(&self, other: &Self) -> Self {
+        (self.clone() - other.clone()).normalized_signed()
+    }
+
+    /// Sine
+    pub fn sin(&self) -> T {
+        self.radians.sin()
+    }
+
+    /// Cosine
+    pub fn cos(&self) -> T {
+        self.radians.cos()
+    }
+
+    /// Tangent
+    pub fn tan(&self) -> T {
+        self.radians.tan()
+    }
+
+    /// Sine and cosine together
+    pub fn sin_cos(&self) -> (T, T) {
+        (self.sin(), self.cos())
+    }
+
+    /// Angle whose sine is `v`, in `[-pi/2, pi/2]`
+    pub fn asin(v: T) -> Self {
+        Angle::from_radians(v.asin())
+    }
+
+    /// Angle whose cosine is `v`, in `[0, pi]`
+    pub fn acos(v: T) -> Self {
+        Angle::from_radians(v.acos())
+    }
+
+    /// Angle of the point `(x, y)` from the positive x axis, in `(-pi, pi]`
+    pub fn atan2(y: T, x: T) -> Self {
+        Angle::from_radians(y.atan2(&x))
+    }
+}
+
+impl<T: Real> Add for Angle<T> {
+    type Output = Angle<T>;
+
+    fn add(self, other: Angle<T>) -> Angle<T> {
+        Angle::from_radians(self.radians + other.radians)
+    }
+}
+
+impl<T: Real> Sub for Angle<T> {
+    type Output = Angle<T>;
+
+    fn sub(self, other: Angle<T>) -> Angle<T> {
+        Angle::from_radians(self.radians - other.radians)
+    }
+}
+
+impl<T: Real> Mul<T> for Angle<T> {
+    type Output = Angle<T>;
+
+    fn mul(self, s: T) -> Angle<T> {
+        Angle::from_radians(self.radians * s)
+    }
+}
+
+impl<T: Real> Div<T> for Angle<T> {
+    type Output = Angle<T>;
+
+    fn div(self, s: T) -> Angle<T> {
+        Angle::from_radians(self.radians / s)
+    }
+}
+
+impl<T: Real> Neg for Angle<T> {
+    type Output = Angle<T>;
+
+    fn neg(self) -> Angle<T> {
+        Angle::from_radians(-self.radians)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_approx_eq;
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    #[test]
+    fn units_test() {
+        let right = Angle::from_degrees(90.0);
+        assert_approx_eq!(right.radians(), FRAC_PI_2);
+        assert_approx_eq!(Angle::from_radians(PI).degrees(), 180.0);
+        assert_approx_eq!(right.sin(), 1.0);
+        assert_approx_eq!(Angle::atan2(1.0, 0.0), right);
+        assert_eq!(right * 2.0, Angle::half_turn());
+    }
+
+    #[test]
+    fn wrapping_test() {
+        let a = Angle::from_degrees(350.0);
+        let b = Angle::from_degrees(20.0);
+        assert_approx_eq!(a.wrapping_add(&b).degrees(), 10.0);
+        assert_approx_eq!(b.wrapping_sub(&a).degrees(), 30.0);
+        assert_approx_eq!(Angle::from_degrees(-90.0).normalized().degrees(), 270.0);
+        assert_approx_eq!(
+            Angle::from_degrees(720.0 + 45.0).normalized().degrees(),
+            45.0
+        );
+        assert_eq!(
+            Angle::<f64>::half_turn().normalized_signed(),
+            Angle::half_turn()
+        );
+        assert_eq!(
+            (-Angle::<f64>::half_turn()).normalized_signed(),
+            Angle::half_turn()
+        );
+        assert_eq!(Angle::<f64>::full_turn().normalized(), Angle::zero());
+    }
+}
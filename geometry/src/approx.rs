@@ -0,0 +1,251 @@
+use crate::angle::Angle;
+use crate::dims::Point;
+use crate::matrix::{Matrix3, Matrix4};
+use crate::pointn::PointN;
+use crate::quaternion::Quaternion;
+use crate::transform::Affine3;
+use crate::vector::Vector3;
+
+/// Absolute tolerance used by [`ApproxEq::approx_eq`]
+pub const DEFAULT_EPSILON: f64 = 1e-12;
+/// Relative tolerance used by [`ApproxEq::approx_eq`]
+pub const DEFAULT_MAX_RELATIVE: f64 = 1e-9;
+/// Units in the last place used by [`assert_ulps_eq!`](crate::assert_ulps_eq)
+pub const DEFAULT_MAX_ULPS: u32 = 4;
+
+/// ApproxEq trait supplies tolerance-aware equality for floating point geometry
+///
+/// Composite types compare component-wise and are equal when every component is.
+pub trait ApproxEq {
+    /// Equal when every component differs by at most `epsilon`
+    fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool;
+
+    /// Equal within `epsilon` or within `max_relative` of the larger magnitude
+    fn relative_eq(&self, other: &Self, epsilon: f64, max_relative: f64) -> bool;
+
+    /// Equal within `epsilon` or at most `max_ulps` representable values apart
+    fn ulps_eq(&self, other: &Self, epsilon: f64, max_ulps: u32) -> bool;
+
+    /// Relative comparison with the default tolerances
+    fn approx_eq(&self, other: &Self) -> bool {
+        self.relative_eq(other, DEFAULT_EPSILON, DEFAULT_MAX_RELATIVE)
+    }
+}
+
+impl ApproxEq for f64 {
+    fn abs_diff_eq(&self, other: &f64, epsilon: f64) -> bool {
+        self == other || (self - other).abs() <= epsilon
+    }
+
+    fn relative_eq(&self, other: &f64, epsilon: f64, max_relative: f64) -> bool {
+        if self.abs_diff_eq(other, epsilon) {
+            return true;
+        }
+        if !self.is_finite() || !other.is_finite() {
+            return false;
+        }
+        (self - other).abs() <= self.abs().max(other.abs()) * max_relative
+    }
+
+    fn ulps_eq(&self, other: &f64, epsilon: f64, max_ulps: u32) -> bool {
+        if self.abs_diff_eq(other, epsilon) {
+            return true;
+        }
+        if self.is_nan() || other.is_nan() || self.is_sign_negative() != other.is_sign_negative() {
+            return false;
+        }
+        let a = self.to_bits() as i64;
+        let b = other.to_bits() as i64;
+        (a - b).unsigned_abs() <= u64::from(max_ulps)
+    }
+}
+
+impl ApproxEq for f32 {
+    fn abs_diff_eq(&self, other: &f32, epsilon: f64) -> bool {
+        f64::from(*self).abs_diff_eq(&f64::from(*other), epsilon)
+    }
+
+    fn relative_eq(&self, other: &f32, epsilon: f64, max_relative: f64) -> bool {
+        f64::from(*self).relative_eq(&f64::from(*other), epsilon, max_relative)
+    }
+
+    fn ulps_eq(&self, other: &f32, epsilon: f64, max_ulps: u32) -> bool {
+        if self.abs_diff_eq(other, epsilon) {
+            return true;
+        }
+        if self.is_nan() || other.is_nan() || self.is_sign_negative() != other.is_sign_negative() {
+            return false;
+        }
+        let a = i64::from(self.to_bits());
+        let b = i64::from(other.to_bits());
+        (a - b).unsigned_abs() <= u64::from(max_ulps)
+    }
+}
+
+impl<T: ApproxEq, const N: usize> ApproxEq for [T; N] {
+    fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self.iter()
+            .zip(other.iter())
+            .all(|(a, b)| a.abs_diff_eq(b, epsilon))
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: f64, max_relative: f64) -> bool {
+        self.iter()
+            .zip(other.iter())
+            .all(|(a, b)| a.relative_eq(b, epsilon, max_relative))
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: f64, max_ulps: u32) -> bool {
+        self.iter()
+            .zip(other.iter())
+            .all(|(a, b)| a.ulps_eq(b, epsilon, max_ulps))
+    }
+}
+
+/// Implements [`ApproxEq`] by comparing the arrays returned by `$components`
+macro_rules! impl_approx_via {
+    ($t:ty, $components:expr) => {
+        impl ApproxEq for $t {
+            fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool {
+                let f = $components;
+                f(self).abs_diff_eq(&f(other), epsilon)
+            }
+
+            fn relative_eq(&self, other: &Self, epsilon: f64, max_relative: f64) -> bool {
+                let f = $components;
+                f(self).relative_eq(&f(other), epsilon, max_relative)
+            }
+
+            fn ulps_eq(&self, other: &Self, epsilon: f64, max_ulps: u32) -> bool {
+                let f = $components;
+                f(self).ulps_eq(&f(other), epsilon, max_ulps)
+            }
+        }
+    };
+}
+
+impl_approx_via!(Angle, |a: &Angle| a.radians());
+impl_approx_via!(Point<f64>, |p: &Point<f64>| p.to_array());
+impl_approx_via!(Point<f32>, |p: &Point<f32>| p.to_array());
+impl_approx_via!(Vector3<f64>, |v: &Vector3<f64>| v.to_array());
+impl_approx_via!(Vector3<f32>, |v: &Vector3<f32>| v.to_array());
+impl_approx_via!(Matrix3, |m: &Matrix3| *m.rows());
+impl_approx_via!(Matrix4, |m: &Matrix4| *m.rows());
+impl_approx_via!(Quaternion, |q: &Quaternion| q.to_array());
+impl_approx_via!(Affine3, |a: &Affine3| *a.to_matrix4().rows());
+
+impl<const D: usize> ApproxEq for PointN<D> {
+    fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self.coords().abs_diff_eq(other.coords(), epsilon)
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: f64, max_relative: f64) -> bool {
+        self.coords()
+            .relative_eq(other.coords(), epsilon, max_relative)
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: f64, max_ulps: u32) -> bool {
+        self.coords().ulps_eq(other.coords(), epsilon, max_ulps)
+    }
+}
+
+/// Asserts two values are equal with the default relative tolerances,
+/// or within an absolute `epsilon` when one is given
+#[macro_export]
+macro_rules! assert_approx_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        match (&$left, &$right) {
+            (l, r) => assert!(
+                $crate::ApproxEq::approx_eq(l, r),
+                "assertion failed: `left ≈ right`\n  left: `{:?}`\n right: `{:?}`",
+                l,
+                r
+            ),
+        }
+    };
+    ($left:expr, $right:expr, $epsilon:expr $(,)?) => {
+        match (&$left, &$right) {
+            (l, r) => assert!(
+                $crate::ApproxEq::abs_diff_eq(l, r, $epsilon),
+                "assertion failed: `left ≈ right` within {:?}\n  left: `{:?}`\n right: `{:?}`",
+                $epsilon,
+                l,
+                r
+            ),
+        }
+    };
+}
+
+/// Asserts two values are equal within an absolute `epsilon` or a relative `max_relative`
+#[macro_export]
+macro_rules! assert_relative_eq {
+    ($left:expr, $right:expr, $epsilon:expr, $max_relative:expr $(,)?) => {
+        match (&$left, &$right) {
+            (l, r) => assert!(
+                $crate::ApproxEq::relative_eq(l, r, $epsilon, $max_relative),
+                "assertion failed: `left ≈ right` within {:?} or {:?} relative\n  left: `{:?}`\n right: `{:?}`",
+                $epsilon,
+                $max_relative,
+                l,
+                r
+            ),
+        }
+    };
+}
+
+/// Asserts two values are at most `max_ulps` representable values apart
+#[macro_export]
+macro_rules! assert_ulps_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_ulps_eq!($left, $right, $crate::approx::DEFAULT_MAX_ULPS)
+    };
+    ($left:expr, $right:expr, $max_ulps:expr $(,)?) => {
+        match (&$left, &$right) {
+            (l, r) => assert!(
+                $crate::ApproxEq::ulps_eq(l, r, 0.0, $max_ulps),
+                "assertion failed: `left ≈ right` within {:?} ulps\n  left: `{:?}`\n right: `{:?}`",
+                $max_ulps,
+                l,
+                r
+            ),
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scalar_comparisons_test() {
+        assert!(0.1_f64.abs_diff_eq(&0.10001, 1e-4));
+        assert!(!0.1_f64.abs_diff_eq(&0.10001, 1e-6));
+        assert!(1e9_f64.relative_eq(&(1e9 + 0.5), 0.0, 1e-9));
+        assert!(!1e-9_f64.relative_eq(&2e-9, 0.0, 1e-3));
+        let next = f64::from_bits(1.0_f64.to_bits() + 2);
+        assert!(1.0_f64.ulps_eq(&next, 0.0, 2));
+        assert!(!1.0_f64.ulps_eq(&next, 0.0, 1));
+        assert!(!f64::NAN.approx_eq(&f64::NAN));
+        assert_ulps_eq!(0.1 + 0.2, 0.3);
+    }
+
+    #[test]
+    fn composite_comparisons_test() {
+        let p = Point::new(0.1 + 0.2, 1.0, -2.0);
+        assert_approx_eq!(p, Point::new(0.3, 1.0, -2.0));
+        assert_approx_eq!(p, Point::new(0.3, 1.0, -2.0 + 1e-7), 1e-6);
+        assert_relative_eq!(
+            Vector3::new(1e6, 0.0, 0.0),
+            Vector3::new(1e6 + 1e-4, 0.0, 0.0),
+            0.0,
+            1e-9
+        );
+        assert!(!p.approx_eq(&Point::new(0.3, 1.0, -2.1)));
+        let q = Quaternion::from_axis_angle(Vector3::unit_z(), Angle::from_radians(0.5));
+        assert_approx_eq!(q.to_matrix().inverse().unwrap(), q.conjugate().to_matrix());
+        assert_approx_eq!(
+            Affine3::rotation_z(Angle::from_radians(0.5)),
+            Affine3::from(q)
+        );
+    }
+}
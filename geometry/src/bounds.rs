@@ -0,0 +1,519 @@
+//! Axis-aligned and oriented bounding boxes
+
+use crate::dimension::{D2, D3};
+use crate::dims::{Dimensional, Point};
+use crate::error::GeometryError;
+use crate::linear::Ray;
+use crate::matrix::Matrix3;
+use crate::pointn::{Point2, PointN};
+use crate::transform::{Affine3, Transformable, TryTransformable};
+use crate::vector::Vector3;
+
+/// Aabb struct represents an axis-aligned box in `N` dimensions
+///
+/// The box is closed, points on its faces are inside. A box may be flat or a
+/// single point when its corners share coordinates.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Aabb<const N: usize> {
+    min: PointN<N>,
+    max: PointN<N>,
+}
+
+/// Axis-aligned rectangle
+pub type Aabb2 = Aabb<2>;
+/// Axis-aligned box in space
+pub type Aabb3 = Aabb<3>;
+
+impl<const N: usize> Aabb<N> {
+    /// Smallest box containing both corners, in any order
+    pub fn new(a: PointN<N>, b: PointN<N>) -> Self {
+        Aabb {
+            min: a.min(&b),
+            max: a.max(&b),
+        }
+    }
+
+    /// Smallest box containing every point, `None` when there are none
+    pub fn from_points<'a>(points: impl IntoIterator<Item = &'a PointN<N>>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = *points.next()?;
+        Some(points.fold(Aabb::new(first, first), |b, p| b.expanded_to(p)))
+    }
+
+    /// Corner with the smallest coordinates
+    pub fn min(&self) -> PointN<N> {
+        self.min
+    }
+
+    /// Corner with the largest coordinates
+    pub fn max(&self) -> PointN<N> {
+        self.max
+    }
+
+    /// Center of the box
+    pub fn center(&self) -> PointN<N> {
+        self.min.lerp(&self.max, 0.5)
+    }
+
+    /// Edge length along each axis
+    pub fn size(&self) -> [f64; N] {
+        std::array::from_fn(|i| self.max[i] - self.min[i])
+    }
+
+    /// Length, area or volume of the box
+    pub fn measure(&self) -> f64 {
+        self.size().iter().product()
+    }
+
+    /// True when `p` lies inside or on the boundary
+    pub fn contains_point(&self, p: &PointN<N>) -> bool {
+        (0..N).all(|i| self.min[i] <= p[i] && p[i] <= self.max[i])
+    }
+
+    /// True when `other` lies entirely inside this box
+    pub fn contains(&self, other: &Self) -> bool {
+        self.contains_point(&other.min) && self.contains_point(&other.max)
+    }
+
+    /// True when the boxes share at least one point
+    pub fn intersects(&self, other: &Self) -> bool {
+        (0..N).all(|i| self.min[i] <= other.max[i] && other.min[i] <= self.max[i])
+    }
+
+    /// Smallest box containing both boxes
+    pub fn union(&self, other: &Self) -> Self {
+        Aabb {
+            min: self.min.min(&other.min),
+            max: self.max.max(&other.max),
+        }
+    }
+
+    /// Region shared by both boxes, `None` when they are disjoint
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        if self.intersects(other) {
+            Some(Aabb {
+                min: self.min.max(&other.min),
+                max: self.max.min(&other.max),
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Smallest box containing this box and `p`
+    pub fn expanded_to(&self, p: &PointN<N>) -> Self {
+        Aabb {
+            min: self.min.min(p),
+            max: self.max.max(p),
+        }
+    }
+
+    /// Box grown by `margin` on every side, a negative margin shrinks it down to its center
+    pub fn expanded(&self, margin: f64) -> Self {
+        let c = self.center();
+        let min = std::array::from_fn(|i| (self.min[i] - margin).min(c[i]));
+        let max = std::array::from_fn(|i| (self.max[i] + margin).max(c[i]));
+        Aabb {
+            min: PointN::from_coords(min),
+            max: PointN::from_coords(max),
+        }
+    }
+
+    /// Parameters `(enter, exit)` where `origin + direction * t` crosses the box
+    ///
+    /// Only `t >= 0` is considered, so `enter` is zero when the origin is inside.
+    /// Returns `None` when the ray misses the box.
+    pub fn ray_hit(&self, origin: &PointN<N>, direction: &[f64; N]) -> Option<(f64, f64)> {
+        let (mut enter, mut exit) = (0.0_f64, f64::INFINITY);
+        for i in 0..N {
+            if direction[i] == 0.0 {
+                if origin[i] < self.min[i] || origin[i] > self.max[i] {
+                    return None;
+                }
+                continue;
+            }
+            let a = (self.min[i] - origin[i]) / direction[i];
+            let b = (self.max[i] - origin[i]) / direction[i];
+            enter = enter.max(a.min(b));
+            exit = exit.min(a.max(b));
+        }
+        if enter <= exit {
+            Some((enter, exit))
+        } else {
+            None
+        }
+    }
+}
+
+impl Aabb2 {
+    /// Box enclosing a planar shape
+    pub fn from_shape<S: Dimensional<Dim = D2> + ?Sized>(shape: &S) -> Self {
+        let (min, max) = shape.bounding_box();
+        Aabb::new(min, max)
+    }
+}
+
+impl Aabb3 {
+    /// Box enclosing a shape in space
+    pub fn from_shape<S: Dimensional<Dim = D3> + ?Sized>(shape: &S) -> Self {
+        let (min, max) = shape.bounding_box();
+        Aabb::new(PointN::from(min), PointN::from(max))
+    }
+
+    /// Distance along `ray` to the first point inside the box, `None` on a miss
+    pub fn intersect_ray(&self, ray: &Ray) -> Option<f64> {
+        let origin = PointN::from(ray.origin());
+        self.ray_hit(&origin, &ray.direction().to_array())
+            .map(|(enter, _)| enter)
+    }
+
+    /// The eight corners
+    pub fn corners(&self) -> [Point; 8] {
+        let (lo, hi) = (self.min, self.max);
+        std::array::from_fn(|i| {
+            let pick = |axis: usize| {
+                if i >> axis & 1 == 1 {
+                    hi[axis]
+                } else {
+                    lo[axis]
+                }
+            };
+            Point::new(pick(0), pick(1), pick(2))
+        })
+    }
+}
+
+impl Dimensional for Aabb2 {
+    type Dim = D2;
+
+    fn measure(&self) -> f64 {
+        Aabb::measure(self)
+    }
+
+    fn bounding_box(&self) -> (Point2, Point2) {
+        (self.min, self.max)
+    }
+
+    fn centroid(&self) -> Point2 {
+        self.center()
+    }
+}
+
+impl Dimensional for Aabb3 {
+    type Dim = D3;
+
+    fn measure(&self) -> f64 {
+        Aabb::measure(self)
+    }
+
+    fn bounding_box(&self) -> (Point, Point) {
+        (Point::from(self.min), Point::from(self.max))
+    }
+
+    fn centroid(&self) -> Point {
+        Point::from(self.center())
+    }
+}
+
+impl Transformable for Aabb3 {
+    /// Smallest box containing the mapped corners, rotations grow the box
+    fn transform(&self, t: &Affine3) -> Self {
+        let corners = self.corners().map(|c| PointN::from(t.apply_point(&c)));
+        corners[1..]
+            .iter()
+            .fold(Aabb::new(corners[0], corners[0]), |b, c| b.expanded_to(c))
+    }
+}
+
+/// Eigenvectors of a symmetric matrix by cyclic Jacobi rotations, as columns
+fn symmetric_eigenvectors(m: &Matrix3) -> Matrix3 {
+    let mut a = *m.rows();
+    let mut v = *Matrix3::identity().rows();
+    for _ in 0..32 {
+        let off = a[0][1].abs() + a[0][2].abs() + a[1][2].abs();
+        if off <= 1e-15 * (a[0][0].abs() + a[1][1].abs() + a[2][2].abs()) {
+            break;
+        }
+        for &(p, q) in &[(0, 1), (0, 2), (1, 2)] {
+            if a[p][q] == 0.0 {
+                continue;
+            }
+            let theta = (a[q][q] - a[p][p]) / (2.0 * a[p][q]);
+            let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+            let c = 1.0 / (t * t + 1.0).sqrt();
+            let s = t * c;
+            for row in a.iter_mut() {
+                let (x, y) = (row[p], row[q]);
+                row[p] = c * x - s * y;
+                row[q] = s * x + c * y;
+            }
+            let (row_p, row_q) = (a[p], a[q]);
+            a[p] = std::array::from_fn(|k| c * row_p[k] - s * row_q[k]);
+            a[q] = std::array::from_fn(|k| s * row_p[k] + c * row_q[k]);
+            for row in v.iter_mut() {
+                let (x, y) = (row[p], row[q]);
+                row[p] = c * x - s * y;
+                row[q] = s * x + c * y;
+            }
+        }
+    }
+    Matrix3::new(v)
+}
+
+/// Obb struct represents a box with arbitrary orientation
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Obb {
+    center: Point,
+    axes: Matrix3,
+    half_extents: Vector3,
+}
+
+impl Obb {
+    /// Box centered at `center` whose local axes are the columns of `axes`
+    ///
+    /// The axes should be orthonormal, `half_extents` holds the distance from
+    /// the center to each face along the matching axis.
+    pub fn new(center: Point, axes: Matrix3, half_extents: Vector3) -> Self {
+        Obb {
+            center,
+            axes,
+            half_extents,
+        }
+    }
+
+    /// Box aligned with the principal axes of a point set, `None` when there are no points
+    ///
+    /// The axes are the eigenvectors of the covariance of the points, which
+    /// gives a tight fit for elongated sets without an exhaustive search.
+    pub fn from_points(points: &[Point]) -> Option<Self> {
+        if points.is_empty() {
+            return None;
+        }
+        let n = points.len() as f64;
+        let mean = points
+            .iter()
+            .fold(Vector3::zero(), |acc, p| acc + p.to_vector())
+            / n;
+        let mut cov = [[0.0; 3]; 3];
+        for p in points {
+            let d = (p.to_vector() - mean).to_array();
+            for (i, row) in cov.iter_mut().enumerate() {
+                for (j, c) in row.iter_mut().enumerate() {
+                    *c += d[i] * d[j] / n;
+                }
+            }
+        }
+        let axes = symmetric_eigenvectors(&Matrix3::new(cov));
+        let local = axes.transpose();
+        let (mut lo, mut hi) = ([f64::INFINITY; 3], [f64::NEG_INFINITY; 3]);
+        for p in points {
+            let q = (local * p.to_vector()).to_array();
+            for i in 0..3 {
+                lo[i] = lo[i].min(q[i]);
+                hi[i] = hi[i].max(q[i]);
+            }
+        }
+        let mid = Vector3::from(std::array::from_fn(|i| (lo[i] + hi[i]) / 2.0));
+        let half_extents = Vector3::from(std::array::from_fn(|i| (hi[i] - lo[i]) / 2.0));
+        Some(Obb::new(Point::from(axes * mid), axes, half_extents))
+    }
+
+    /// Center of the box
+    pub fn center(&self) -> Point {
+        self.center
+    }
+
+    /// Unit axes of the box
+    pub fn axes(&self) -> [Vector3; 3] {
+        [
+            self.axes.column(0),
+            self.axes.column(1),
+            self.axes.column(2),
+        ]
+    }
+
+    /// Distance from the center to each face along the matching axis
+    pub fn half_extents(&self) -> Vector3 {
+        self.half_extents
+    }
+
+    /// Volume of the box
+    pub fn volume(&self) -> f64 {
+        let [x, y, z] = self.half_extents.to_array();
+        8.0 * x * y * z
+    }
+
+    /// The eight corners
+    pub fn corners(&self) -> [Point; 8] {
+        let [ax, ay, az] = self.axes();
+        let [x, y, z] = self.half_extents.to_array();
+        std::array::from_fn(|i| {
+            let sign = |axis: usize| if i >> axis & 1 == 1 { 1.0 } else { -1.0 };
+            self.center + ax * (x * sign(0)) + ay * (y * sign(1)) + az * (z * sign(2))
+        })
+    }
+
+    /// Coordinates of `p` along the box axes, relative to the center
+    fn local(&self, p: &Point) -> [f64; 3] {
+        (self.axes.transpose() * (*p - self.center)).to_array()
+    }
+
+    /// True when `p` lies inside or on the boundary, within rounding error
+    pub fn contains_point(&self, p: &Point) -> bool {
+        let q = self.local(p);
+        let h = self.half_extents.to_array();
+        (0..3).all(|i| q[i].abs() <= h[i] * (1.0 + 1e-12) + 1e-12)
+    }
+
+    /// Point of the box closest to `p`, `p` itself when it is inside
+    pub fn closest_point(&self, p: &Point) -> Point {
+        let q = self.local(p);
+        let h = self.half_extents.to_array();
+        let clamped = Vector3::from(std::array::from_fn(|i| q[i].clamp(-h[i], h[i])));
+        self.center + self.axes * clamped
+    }
+}
+
+impl Dimensional for Obb {
+    type Dim = D3;
+
+    fn measure(&self) -> f64 {
+        self.volume()
+    }
+
+    fn bounding_box(&self) -> (Point, Point) {
+        let corners = self.corners();
+        corners[1..]
+            .iter()
+            .fold((corners[0], corners[0]), |(lo, hi), c| {
+                (lo.min(c), hi.max(c))
+            })
+    }
+
+    fn centroid(&self) -> Point {
+        self.center
+    }
+}
+
+impl TryTransformable for Obb {
+    /// Maps the center and axes, rescaling the half extents by each axis' stretch
+    ///
+    /// Fails with [`GeometryError::UnsupportedTransform`] when the mapped axes
+    /// are no longer perpendicular and with [`GeometryError::Degenerate`] when
+    /// `t` collapses an axis.
+    fn try_transform(&self, t: &Affine3) -> Result<Self, GeometryError> {
+        let mapped = self.axes().map(|a| t.apply_vector(&a));
+        let lengths = mapped.map(|a| a.length());
+        if lengths.contains(&0.0) {
+            return Err(GeometryError::Degenerate);
+        }
+        for &(i, j) in &[(0, 1), (0, 2), (1, 2)] {
+            if mapped[i].dot(&mapped[j]).abs() > 1e-9 * lengths[i] * lengths[j] {
+                return Err(GeometryError::UnsupportedTransform);
+            }
+        }
+        let [x, y, z] = [0, 1, 2].map(|i| mapped[i] / lengths[i]);
+        let h = self.half_extents.to_array();
+        Ok(Obb::new(
+            t.apply_point(&self.center),
+            Matrix3::from_columns(x, y, z),
+            Vector3::from(std::array::from_fn(|i| h[i] * lengths[i])),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::approx::ApproxEq;
+    use crate::pointn::Point3;
+
+    #[test]
+    fn aabb_test() {
+        let a = Aabb2::new(Point2::new(2.0, 0.0), Point2::new(0.0, 2.0));
+        let b = Aabb2::new(Point2::new(1.0, 1.0), Point2::new(3.0, 4.0));
+        assert_eq!(a.min(), Point2::new(0.0, 0.0));
+        assert_eq!(a.union(&b).measure(), 12.0);
+        assert_eq!(
+            a.intersection(&b),
+            Some(Aabb::new(Point2::new(1.0, 1.0), Point2::new(2.0, 2.0)))
+        );
+        assert!(a.contains(&a.intersection(&b).unwrap()) && !a.contains(&b));
+        assert_eq!(a.expanded(1.0).size(), [4.0, 4.0]);
+        assert_eq!(a.expanded(-5.0).measure(), 0.0);
+        let far = Aabb2::new(Point2::new(5.0, 5.0), Point2::new(6.0, 6.0));
+        assert_eq!(a.intersection(&far), None);
+        let points = [Point2::new(1.0, -1.0), Point2::new(-2.0, 3.0)];
+        assert_eq!(Aabb::from_points(&points).unwrap().size(), [3.0, 4.0]);
+        assert_eq!(Aabb2::from_points(&[]), None);
+    }
+
+    #[test]
+    fn aabb_ray_test() {
+        let cube = Aabb3::new(Point3::new(1.0, 1.0, 1.0), Point3::new(2.0, 2.0, 2.0));
+        let ray = Ray::new(Point::origin(), Vector3::new(1.0, 1.0, 1.0)).unwrap();
+        assert!((cube.intersect_ray(&ray).unwrap() - 3f64.sqrt()).abs() < 1e-12);
+        let miss = Ray::new(Point::origin(), Vector3::unit_x()).unwrap();
+        assert_eq!(cube.intersect_ray(&miss), None);
+        let inside = Ray::new(Point::new(1.5, 1.5, 1.5), Vector3::unit_z()).unwrap();
+        assert_eq!(cube.intersect_ray(&inside), Some(0.0));
+        let behind = Ray::new(Point::new(3.0, 1.5, 1.5), Vector3::unit_x()).unwrap();
+        assert_eq!(cube.intersect_ray(&behind), None);
+        assert_eq!(
+            Aabb3::from_shape(&cube).corners()[7],
+            Point::new(2.0, 2.0, 2.0)
+        );
+    }
+
+    #[test]
+    fn obb_test() {
+        // A 4 x 2 x 0 rectangle rotated 45 degrees about z
+        let (c, s) = (0.5f64.sqrt(), 0.5f64.sqrt());
+        let points: Vec<Point> = [
+            (-2.0, -1.0),
+            (2.0, -1.0),
+            (2.0, 1.0),
+            (-2.0, 1.0),
+            (0.0, 0.0),
+        ]
+        .iter()
+        .map(|&(x, y)| Point::new(c * x - s * y + 10.0, s * x + c * y, 3.0))
+        .collect();
+        let obb = Obb::from_points(&points).unwrap();
+        assert!(obb.center().abs_diff_eq(&Point::new(10.0, 0.0, 3.0), 1e-9));
+        let mut h = obb.half_extents().to_array();
+        h.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert!(h.abs_diff_eq(&[0.0, 1.0, 2.0], 1e-9));
+        assert!(points.iter().all(|p| obb.contains_point(p)));
+        assert!(!obb.contains_point(&Point::new(12.0, 0.0, 3.0)));
+        let outside = Point::new(10.0, 0.0, 5.0);
+        assert!(obb
+            .closest_point(&outside)
+            .abs_diff_eq(&Point::new(10.0, 0.0, 3.0), 1e-9));
+        let (lo, hi) = obb.bounding_box();
+        assert!((hi.x() - lo.x() - 6.0 * c).abs() < 1e-9);
+        assert_eq!(obb.measure(), 0.0);
+    }
+
+    #[test]
+    fn transform_test() {
+        let cube = Aabb3::new(Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 1.0, 1.0));
+        let turned = cube.rotated(Vector3::unit_z(), crate::angle::Angle::from_degrees(45.0));
+        assert!((turned.size()[0] - 2f64.sqrt()).abs() < 1e-12);
+        assert_eq!(turned.size()[2], 1.0);
+        let obb = Obb::new(
+            Point::origin(),
+            Matrix3::identity(),
+            Vector3::new(1.0, 2.0, 3.0),
+        );
+        let stretched = obb.try_transform(&Affine3::scaling(2.0, 1.0, 1.0)).unwrap();
+        assert_eq!(stretched.half_extents(), Vector3::new(2.0, 2.0, 3.0));
+        let spun = obb
+            .try_rotated(Vector3::unit_z(), crate::angle::Angle::from_degrees(30.0))
+            .unwrap();
+        assert!((spun.volume() - obb.volume()).abs() < 1e-12);
+        let sheared = spun.try_transform(&Affine3::scaling(2.0, 1.0, 1.0));
+        assert_eq!(sheared, Err(GeometryError::UnsupportedTransform));
+        assert_eq!(obb.try_scaled(0.0), Err(GeometryError::Degenerate));
+    }
+}
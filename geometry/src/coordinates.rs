@@ -0,0 +1,213 @@
+//! Polar, cylindrical and spherical coordinates
+//!
+//! Angles are in radians. Converting from a point always yields a
+//! non-negative radius and azimuth in `(-pi, pi]`. Where the azimuth is
+//! undefined, at the origin and on the z axis, it is reported as zero, and
+//! the inclination of the origin is zero as well.
+
+use crate::dims::Point;
+use crate::pointn::Point2;
+
+/// Angle of `(x, y)` from the positive x axis, zero at the origin
+fn azimuth(x: f64, y: f64) -> f64 {
+    if x == 0.0 && y == 0.0 {
+        0.0
+    } else {
+        y.atan2(x)
+    }
+}
+
+/// Polar struct represents a point in the plane by distance and angle from the origin
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Polar {
+    radius: f64,
+    angle: f64,
+}
+
+impl Polar {
+    /// Creates polar coordinates, a negative radius points the opposite way
+    pub fn new(radius: f64, angle: f64) -> Self {
+        Polar { radius, angle }
+    }
+
+    /// Distance from the origin
+    pub fn radius(&self) -> f64 {
+        self.radius
+    }
+
+    /// Counter-clockwise angle from the positive x axis
+    pub fn angle(&self) -> f64 {
+        self.angle
+    }
+}
+
+impl From<Point2> for Polar {
+    fn from(p: Point2) -> Self {
+        Polar::new(p.x().hypot(p.y()), azimuth(p.x(), p.y()))
+    }
+}
+
+impl From<Polar> for Point2 {
+    fn from(p: Polar) -> Self {
+        let (s, c) = p.angle.sin_cos();
+        Point2::new(p.radius * c, p.radius * s)
+    }
+}
+
+/// Cylindrical struct represents a point by polar coordinates in the xy plane and a height along z
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Cylindrical {
+    radius: f64,
+    azimuth: f64,
+    height: f64,
+}
+
+impl Cylindrical {
+    /// Creates cylindrical coordinates
+    pub fn new(radius: f64, azimuth: f64, height: f64) -> Self {
+        Cylindrical {
+            radius,
+            azimuth,
+            height,
+        }
+    }
+
+    /// Distance from the z axis
+    pub fn radius(&self) -> f64 {
+        self.radius
+    }
+
+    /// Counter-clockwise angle from the positive x axis
+    pub fn azimuth(&self) -> f64 {
+        self.azimuth
+    }
+
+    /// Z coordinate
+    pub fn height(&self) -> f64 {
+        self.height
+    }
+}
+
+impl From<Point> for Cylindrical {
+    fn from(p: Point) -> Self {
+        Cylindrical::new(p.x().hypot(p.y()), azimuth(p.x(), p.y()), p.z())
+    }
+}
+
+impl From<Cylindrical> for Point {
+    fn from(c: Cylindrical) -> Self {
+        let (s, co) = c.azimuth.sin_cos();
+        Point::new(c.radius * co, c.radius * s, c.height)
+    }
+}
+
+/// Spherical struct represents a point by radius, inclination from +z and azimuth
+///
+/// Follows the ISO 80000-2 convention, the inclination is `0` on the positive
+/// z axis and `pi` on the negative z axis.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Spherical {
+    radius: f64,
+    inclination: f64,
+    azimuth: f64,
+}
+
+impl Spherical {
+    /// Creates spherical coordinates
+    pub fn new(radius: f64, inclination: f64, azimuth: f64) -> Self {
+        Spherical {
+            radius,
+            inclination,
+            azimuth,
+        }
+    }
+
+    /// Distance from the origin
+    pub fn radius(&self) -> f64 {
+        self.radius
+    }
+
+    /// Angle from the positive z axis, in `[0, pi]` for converted points
+    pub fn inclination(&self) -> f64 {
+        self.inclination
+    }
+
+    /// Counter-clockwise angle of the xy projection from the positive x axis
+    pub fn azimuth(&self) -> f64 {
+        self.azimuth
+    }
+}
+
+impl From<Point> for Spherical {
+    fn from(p: Point) -> Self {
+        let rho = p.x().hypot(p.y());
+        let inclination = if rho == 0.0 && p.z() == 0.0 {
+            0.0
+        } else {
+            rho.atan2(p.z())
+        };
+        Spherical::new(rho.hypot(p.z()), inclination, azimuth(p.x(), p.y()))
+    }
+}
+
+impl From<Spherical> for Point {
+    fn from(s: Spherical) -> Self {
+        let (si, ci) = s.inclination.sin_cos();
+        let (sa, ca) = s.azimuth.sin_cos();
+        Point::new(s.radius * si * ca, s.radius * si * sa, s.radius * ci)
+    }
+}
+
+impl From<Cylindrical> for Spherical {
+    fn from(c: Cylindrical) -> Self {
+        Spherical::from(Point::from(c))
+    }
+}
+
+impl From<Spherical> for Cylindrical {
+    fn from(s: Spherical) -> Self {
+        Cylindrical::from(Point::from(s))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_approx_eq;
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    #[test]
+    fn roundtrip_test() {
+        let p = Point2::new(-1.0, 1.0);
+        let polar = Polar::from(p);
+        assert_approx_eq!(polar.radius(), 2f64.sqrt());
+        assert_approx_eq!(polar.angle(), 3.0 * PI / 4.0);
+        assert_approx_eq!(Point2::from(polar), p);
+        let q = Point::new(1.0, -2.0, 3.0);
+        assert_approx_eq!(Point::from(Cylindrical::from(q)), q);
+        assert_approx_eq!(Point::from(Spherical::from(q)), q);
+        let s = Spherical::new(2.0, FRAC_PI_2, FRAC_PI_2);
+        assert_approx_eq!(Point::from(s), Point::new(0.0, 2.0, 0.0));
+    }
+
+    #[test]
+    fn pole_and_origin_test() {
+        assert_eq!(Polar::from(Point2::new(-0.0, 0.0)), Polar::new(0.0, 0.0));
+        assert_eq!(
+            Spherical::from(Point::<f64>::origin()),
+            Spherical::default()
+        );
+        assert_eq!(
+            Spherical::from(Point::new(-0.0, 0.0, 5.0)),
+            Spherical::new(5.0, 0.0, 0.0)
+        );
+        assert_eq!(
+            Spherical::from(Point::new(0.0, 0.0, -5.0)),
+            Spherical::new(5.0, PI, 0.0)
+        );
+        assert_eq!(
+            Cylindrical::from(Point::new(0.0, 0.0, 2.0)),
+            Cylindrical::new(0.0, 0.0, 2.0)
+        );
+    }
+}
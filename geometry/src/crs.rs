@@ -0,0 +1,354 @@
+//! Coordinate reference system tagging and reprojection
+//!
+//! A [`Crs`] is an EPSG-style numeric code. [`Tagged`] attaches one to a point,
+//! collection or shape, and operations on tagged values refuse to mix codes.
+//! [`CrsRegistry`] knows how to express each registered code in earth-centered,
+//! earth-fixed coordinates and reprojects whole shapes through that frame.
+//!
+//! Every system stores positions as [`Point`]s:
+//!
+//! * geographic: longitude and latitude in degrees, altitude in meters
+//! * geocentric: earth-centered, earth-fixed meters
+//! * projected: easting, northing and altitude in meters
+//! * local: East-North-Up meters from the frame reference
+//!
+//! Datum shifts are not modelled, every system shares one geocentric frame.
+
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fmt;
+use std::sync::Arc;
+
+use crate::dims::Point;
+use crate::error::GeometryError;
+use crate::finite::FinitePoint;
+use crate::geodetic::{Ellipsoid, EnuFrame, Geodetic};
+use crate::pointn::Point2;
+use crate::projection::{Projection, Utm, WebMercator};
+
+/// Crs struct represents a coordinate reference system by numeric code
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Crs(u32);
+
+impl Crs {
+    /// Geographic longitude, latitude and ellipsoidal height on WGS84, EPSG:4979
+    pub const WGS84: Crs = Crs(4979);
+    /// Earth-centered, earth-fixed on WGS84, EPSG:4978
+    pub const ECEF: Crs = Crs(4978);
+    /// Web Mercator, EPSG:3857
+    pub const WEB_MERCATOR: Crs = Crs(3857);
+
+    /// Creates a reference system tag from its code
+    pub const fn new(code: u32) -> Self {
+        Crs(code)
+    }
+
+    /// WGS84 UTM zone, EPSG:326xx in the north and EPSG:327xx in the south
+    pub fn utm(utm: Utm) -> Self {
+        let base = if utm.is_north() { 32600 } else { 32700 };
+        Crs(base + u32::from(utm.zone()))
+    }
+
+    /// Numeric code
+    pub const fn code(&self) -> u32 {
+        self.0
+    }
+}
+
+impl fmt::Display for Crs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "EPSG:{}", self.0)
+    }
+}
+
+/// Tagged struct represents a value whose coordinates are expressed in a known [`Crs`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tagged<S> {
+    crs: Crs,
+    value: S,
+}
+
+impl<S> Tagged<S> {
+    /// Attaches a reference system to a value
+    pub fn new(crs: Crs, value: S) -> Self {
+        Tagged { crs, value }
+    }
+
+    /// Reference system of the value
+    pub fn crs(&self) -> Crs {
+        self.crs
+    }
+
+    /// The tagged value
+    pub fn value(&self) -> &S {
+        &self.value
+    }
+
+    /// Removes the tag, returning the value
+    pub fn into_inner(self) -> S {
+        self.value
+    }
+
+    /// Fails with [`GeometryError::CrsMismatch`] unless both values share a reference system
+    pub fn check_same<U>(&self, other: &Tagged<U>) -> Result<(), GeometryError> {
+        if self.crs == other.crs {
+            Ok(())
+        } else {
+            Err(GeometryError::CrsMismatch {
+                expected: self.crs,
+                found: other.crs,
+            })
+        }
+    }
+
+    /// Combines two values in the same reference system, tagging the result with it
+    pub fn combine<U, R>(
+        &self,
+        other: &Tagged<U>,
+        f: impl FnOnce(&S, &U) -> R,
+    ) -> Result<Tagged<R>, GeometryError> {
+        self.check_same(other)?;
+        Ok(Tagged::new(self.crs, f(&self.value, &other.value)))
+    }
+
+    /// Transforms the value, keeping its reference system
+    pub fn map<R>(self, f: impl FnOnce(S) -> R) -> Tagged<R> {
+        Tagged::new(self.crs, f(self.value))
+    }
+}
+
+impl Tagged<Vec<Point>> {
+    /// Appends the points of `other`, failing when the reference systems differ
+    pub fn extend_from(&mut self, other: &Tagged<Vec<Point>>) -> Result<(), GeometryError> {
+        self.check_same(other)?;
+        self.value.extend_from_slice(&other.value);
+        Ok(())
+    }
+}
+
+/// Reprojectable trait supplies point-wise mapping of whole shapes
+///
+/// Implementations map every defining point and revalidate the result, so a
+/// mapping can fail when a coordinate is not representable or the shape
+/// collapses.
+pub trait Reprojectable: Sized {
+    /// Copy of the shape with every point passed through `f`
+    fn map_points(
+        &self,
+        f: &mut dyn FnMut(&Point) -> Result<Point, GeometryError>,
+    ) -> Result<Self, GeometryError>;
+}
+
+impl Reprojectable for Point {
+    fn map_points(
+        &self,
+        f: &mut dyn FnMut(&Point) -> Result<Point, GeometryError>,
+    ) -> Result<Self, GeometryError> {
+        f(self)
+    }
+}
+
+impl Reprojectable for FinitePoint {
+    fn map_points(
+        &self,
+        f: &mut dyn FnMut(&Point) -> Result<Point, GeometryError>,
+    ) -> Result<Self, GeometryError> {
+        FinitePoint::try_from(f(self.as_point())?)
+    }
+}
+
+impl<S: Reprojectable> Reprojectable for Vec<S> {
+    fn map_points(
+        &self,
+        f: &mut dyn FnMut(&Point) -> Result<Point, GeometryError>,
+    ) -> Result<Self, GeometryError> {
+        self.iter().map(|s| s.map_points(f)).collect()
+    }
+}
+
+/// CrsDefinition enum list of ways a reference system relates to the earth
+#[derive(Clone)]
+pub enum CrsDefinition {
+    /// Longitude, latitude and altitude on an ellipsoid
+    Geographic(Ellipsoid),
+    /// Earth-centered, earth-fixed
+    Geocentric,
+    /// A map projection of WGS84 geographic coordinates, altitude passes through
+    Projected(Arc<dyn Projection + Send + Sync>),
+    /// East-North-Up frame anchored at a reference
+    Local(EnuFrame),
+}
+
+impl fmt::Debug for CrsDefinition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CrsDefinition::Geographic(e) => f.debug_tuple("Geographic").field(e).finish(),
+            CrsDefinition::Geocentric => write!(f, "Geocentric"),
+            CrsDefinition::Projected(_) => write!(f, "Projected(..)"),
+            CrsDefinition::Local(frame) => f.debug_tuple("Local").field(frame).finish(),
+        }
+    }
+}
+
+impl CrsDefinition {
+    /// Earth-centered, earth-fixed position of a point in this system
+    fn ecef_of(&self, p: &Point) -> Point {
+        match self {
+            CrsDefinition::Geographic(e) => e.to_ecef(&Geodetic::new(p.y(), p.x(), p.z())),
+            CrsDefinition::Geocentric => *p,
+            CrsDefinition::Projected(projection) => {
+                let g = projection.unproject(&Point2::new(p.x(), p.y()));
+                Geodetic::new(g.latitude(), g.longitude(), p.z()).to_ecef()
+            }
+            CrsDefinition::Local(frame) => frame.enu_to_ecef(p),
+        }
+    }
+
+    /// Position in this system of an earth-centered, earth-fixed point
+    fn position_of(&self, p: &Point) -> Point {
+        match self {
+            CrsDefinition::Geographic(e) => {
+                let g = e.to_geodetic(p);
+                Point::new(g.longitude(), g.latitude(), g.altitude())
+            }
+            CrsDefinition::Geocentric => *p,
+            CrsDefinition::Projected(projection) => {
+                let g = Geodetic::from_ecef(p);
+                let q = projection.project(&g);
+                Point::new(q.x(), q.y(), g.altitude())
+            }
+            CrsDefinition::Local(frame) => frame.ecef_to_enu(p),
+        }
+    }
+}
+
+/// CrsRegistry struct represents the set of reference systems available for reprojection
+#[derive(Debug, Clone)]
+pub struct CrsRegistry {
+    definitions: HashMap<Crs, CrsDefinition>,
+}
+
+impl CrsRegistry {
+    /// Registry without any reference systems
+    pub fn empty() -> Self {
+        CrsRegistry {
+            definitions: HashMap::new(),
+        }
+    }
+
+    /// Registry with WGS84 geographic, ECEF, Web Mercator and every WGS84 UTM zone
+    pub fn new() -> Self {
+        let mut registry = CrsRegistry::empty();
+        registry.register(Crs::WGS84, CrsDefinition::Geographic(Ellipsoid::WGS84));
+        registry.register(Crs::ECEF, CrsDefinition::Geocentric);
+        registry.register(
+            Crs::WEB_MERCATOR,
+            CrsDefinition::Projected(Arc::new(WebMercator)),
+        );
+        for zone in 1..=60 {
+            for &north in &[true, false] {
+                let utm = Utm::new(zone, north).expect("zone in range");
+                registry.register(Crs::utm(utm), CrsDefinition::Projected(Arc::new(utm)));
+            }
+        }
+        registry
+    }
+
+    /// Adds or replaces a reference system
+    pub fn register(&mut self, crs: Crs, definition: CrsDefinition) {
+        self.definitions.insert(crs, definition);
+    }
+
+    /// Definition of a registered reference system
+    pub fn get(&self, crs: Crs) -> Option<&CrsDefinition> {
+        self.definitions.get(&crs)
+    }
+
+    fn lookup(&self, crs: Crs) -> Result<&CrsDefinition, GeometryError> {
+        self.get(crs).ok_or(GeometryError::UnknownCrs(crs))
+    }
+
+    /// Converts a single point between reference systems
+    pub fn transform_point(&self, from: Crs, to: Crs, p: &Point) -> Result<Point, GeometryError> {
+        let (source, target) = (self.lookup(from)?, self.lookup(to)?);
+        if from == to {
+            return Ok(*p);
+        }
+        let q = target.position_of(&source.ecef_of(p));
+        if q.to_array().iter().all(|v| v.is_finite()) {
+            Ok(q)
+        } else {
+            Err(GeometryError::NonFinite)
+        }
+    }
+
+    /// Reprojects a whole tagged shape into another reference system
+    pub fn reproject<S: Reprojectable>(
+        &self,
+        shape: &Tagged<S>,
+        to: Crs,
+    ) -> Result<Tagged<S>, GeometryError> {
+        let from = shape.crs();
+        let value = shape
+            .value()
+            .map_points(&mut |p| self.transform_point(from, to, p))?;
+        Ok(Tagged::new(to, value))
+    }
+}
+
+impl Default for CrsRegistry {
+    fn default() -> Self {
+        CrsRegistry::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::approx::ApproxEq;
+
+    #[test]
+    fn tagging_test() {
+        let mut a = Tagged::new(Crs::WGS84, vec![Point::new(8.0, 47.0, 0.0)]);
+        let b = Tagged::new(Crs::WGS84, vec![Point::new(9.0, 47.5, 0.0)]);
+        let c = Tagged::new(Crs::WEB_MERCATOR, vec![Point::<f64>::origin()]);
+        assert!(a.extend_from(&b).is_ok());
+        assert_eq!(a.value().len(), 2);
+        assert_eq!(
+            a.extend_from(&c),
+            Err(GeometryError::CrsMismatch {
+                expected: Crs::WGS84,
+                found: Crs::WEB_MERCATOR
+            })
+        );
+        let d = a.combine(&b, |x, y| x.len() + y.len()).unwrap();
+        assert_eq!((d.crs(), *d.value()), (Crs::WGS84, 3));
+        assert_eq!(
+            Crs::utm(Utm::new(32, true).unwrap()).to_string(),
+            "EPSG:32632"
+        );
+    }
+
+    #[test]
+    fn reproject_test() {
+        let registry = CrsRegistry::new();
+        let zurich = Point::new(8.5417, 47.3769, 408.0);
+        let ecef = registry
+            .transform_point(Crs::WGS84, Crs::ECEF, &zurich)
+            .unwrap();
+        assert!(ecef.abs_diff_eq(&Geodetic::new(47.3769, 8.5417, 408.0).to_ecef(), 1e-6));
+        let shape = Tagged::new(Crs::WGS84, vec![zurich, Point::new(8.55, 47.38, 420.0)]);
+        let utm = Crs::utm(Utm::for_geodetic(&Geodetic::new(47.3769, 8.5417, 0.0)));
+        let projected = registry.reproject(&shape, utm).unwrap();
+        assert_eq!(projected.crs(), utm);
+        assert!((projected.value()[0].z() - 408.0).abs() < 1e-6);
+        let back = registry.reproject(&projected, Crs::WGS84).unwrap();
+        for (p, q) in back.value().iter().zip(shape.value()) {
+            assert!(p.abs_diff_eq(q, 1e-6));
+        }
+        assert_eq!(
+            registry.reproject(&shape, Crs::new(1)),
+            Err(GeometryError::UnknownCrs(Crs::new(1)))
+        );
+    }
+}
@@ -0,0 +1,77 @@
+use std::fmt::Debug;
+
+use crate::dims::{Dimensional, Point, Type};
+use crate::pointn::{Point1, Point2};
+
+/// Dimension trait ties a zero-sized marker to its runtime [`Type`]
+pub trait Dimension: Debug + Clone + Copy + PartialEq + Eq + Default {
+    /// Runtime dimension type
+    const TYPE: Type;
+
+    /// `f64` point type with this many coordinates
+    type Point;
+}
+
+/// Marker for one dimensional space
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Hash)]
+pub struct D1;
+
+/// Marker for two dimensional space
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Hash)]
+pub struct D2;
+
+/// Marker for three dimensional space
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Hash)]
+pub struct D3;
+
+impl Dimension for D1 {
+    const TYPE: Type = Type::D1;
+    type Point = Point1;
+}
+
+impl Dimension for D2 {
+    const TYPE: Type = Type::D2;
+    type Point = Point2;
+}
+
+impl Dimension for D3 {
+    const TYPE: Type = Type::D3;
+    type Point = Point;
+}
+
+/// SameDimension trait is implemented for pairs of shapes living in the same space
+///
+/// Use it as a bound to reject mismatched dimensions at compile time:
+///
+/// ```compile_fail
+/// use geometry::{Point, Point2, SameDimension};
+///
+/// fn combine<A: SameDimension<B>, B>(_: &A, _: &B) {}
+///
+/// combine(&Point::new(0.0, 0.0, 0.0), &Point2::new(0.0, 0.0));
+/// ```
+pub trait SameDimension<Rhs> {}
+
+impl<A, B> SameDimension<B> for A
+where
+    A: Dimensional,
+    B: Dimensional<Dim = A::Dim>,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pointn::Point3;
+
+    fn space_of<A: SameDimension<B> + Dimensional, B>(_: &A, _: &B) -> Type {
+        <A::Dim as Dimension>::TYPE
+    }
+
+    #[test]
+    fn same_dimension_test() {
+        let p = Point::new(1.0, 2.0, 3.0);
+        assert_eq!(space_of(&p, &Point3::from(p)), Type::D3);
+        assert_eq!(space_of(&Point2::new(0.0, 1.0), &Point2::<i64>::origin()), Type::D2);
+    }
+}
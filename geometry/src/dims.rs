@@ -1,19 +1,257 @@
+use std::ops::{Add, Mul, Neg, Sub};
+
+use crate::dimension::{Dimension, D3};
+use crate::scalar::{Real, Scalar};
+use crate::vector::Vector3;
+
 /// Point struct represents a point in three dimensional space
-#[allow(dead_code)]
-pub struct Point {
-    x: f64,
-    y: f64,
-    z: f64,
+///
+/// The coordinate type defaults to `f64`, any [`Scalar`] can be used instead.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Point<T = f64> {
+    x: T,
+    y: T,
+    z: T,
+}
+
+impl<T: Scalar> Point<T> {
+    /// Creates a point from its three coordinates
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Point { x, y, z }
+    }
+
+    /// The point at the origin of the coordinate system
+    pub fn origin() -> Self {
+        Point::new(T::zero(), T::zero(), T::zero())
+    }
+
+    /// X coordinate
+    pub fn x(&self) -> T {
+        self.x.clone()
+    }
+
+    /// Y coordinate
+    pub fn y(&self) -> T {
+        self.y.clone()
+    }
+
+    /// Z coordinate
+    pub fn z(&self) -> T {
+        self.z.clone()
+    }
+
+    /// Coordinates as an `[x, y, z]` array
+    pub fn to_array(&self) -> [T; 3] {
+        [self.x(), self.y(), self.z()]
+    }
+
+    /// Position vector from the origin to this point
+    pub fn to_vector(&self) -> Vector3<T> {
+        Vector3::new(self.x(), self.y(), self.z())
+    }
+
+    /// Converts every coordinate to the nearest `f64`
+    pub fn to_f64(&self) -> Point {
+        Point::new(self.x.to_f64(), self.y.to_f64(), self.z.to_f64())
+    }
+
+    /// Copy of an `f64` point, `None` when a coordinate does not fit, see [`Scalar::try_from_f64`]
+    pub fn from_f64(p: &Point) -> Option<Self> {
+        Some(Point::new(
+            T::try_from_f64(p.x)?,
+            T::try_from_f64(p.y)?,
+            T::try_from_f64(p.z)?,
+        ))
+    }
+
+    /// Component-wise minimum of two points
+    pub fn min(&self, other: &Self) -> Self {
+        Point::new(
+            self.x().min_of(other.x()),
+            self.y().min_of(other.y()),
+            self.z().min_of(other.z()),
+        )
+    }
+
+    /// Component-wise maximum of two points
+    pub fn max(&self, other: &Self) -> Self {
+        Point::new(
+            self.x().max_of(other.x()),
+            self.y().max_of(other.y()),
+            self.z().max_of(other.z()),
+        )
+    }
+
+    /// Squared Euclidean distance, avoids the square root
+    pub fn distance_squared(&self, other: &Self) -> T {
+        (other.clone() - self.clone()).length_squared()
+    }
+
+    /// Linear interpolation, `t = 0` gives `self` and `t = 1` gives `other`
+    pub fn lerp(&self, other: &Self, t: T) -> Self {
+        self.clone() + (other.clone() - self.clone()) * t
+    }
+}
+
+impl<T: Real> Point<T> {
+    /// Euclidean distance between two points
+    pub fn distance(&self, other: &Self) -> T {
+        self.distance_squared(other).sqrt()
+    }
+}
+
+impl<T> From<[T; 3]> for Point<T> {
+    fn from(c: [T; 3]) -> Self {
+        let [x, y, z] = c;
+        Point { x, y, z }
+    }
+}
+
+impl<T: Scalar> From<Vector3<T>> for Point<T> {
+    fn from(v: Vector3<T>) -> Self {
+        Point::new(v.x(), v.y(), v.z())
+    }
+}
+
+impl<T: Scalar> Add<Vector3<T>> for Point<T> {
+    type Output = Point<T>;
+
+    fn add(self, v: Vector3<T>) -> Point<T> {
+        Point::new(self.x + v.x(), self.y + v.y(), self.z + v.z())
+    }
+}
+
+impl<T: Scalar> Sub<Vector3<T>> for Point<T> {
+    type Output = Point<T>;
+
+    fn sub(self, v: Vector3<T>) -> Point<T> {
+        Point::new(self.x - v.x(), self.y - v.y(), self.z - v.z())
+    }
+}
+
+impl<T: Scalar> Sub for Point<T> {
+    type Output = Vector3<T>;
+
+    fn sub(self, other: Point<T>) -> Vector3<T> {
+        Vector3::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl<T: Scalar> Mul<T> for Point<T> {
+    type Output = Point<T>;
+
+    fn mul(self, s: T) -> Point<T> {
+        Point::new(self.x * s.clone(), self.y * s.clone(), self.z * s)
+    }
+}
+
+impl<T: Scalar> Neg for Point<T> {
+    type Output = Point<T>;
+
+    fn neg(self) -> Point<T> {
+        Point::new(-self.x, -self.y, -self.z)
+    }
 }
 
 /// Type enum list of supported dimensions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Type {
     D1,
     D2,
     D3,
 }
 
+impl Type {
+    /// Number of coordinates a point of this dimension stores
+    pub const fn dimension(&self) -> usize {
+        match self {
+            Type::D1 => 1,
+            Type::D2 => 2,
+            Type::D3 => 3,
+        }
+    }
+
+    /// Dimension type for a coordinate count, `None` when unsupported
+    pub const fn from_dimension(n: usize) -> Option<Type> {
+        match n {
+            1 => Some(Type::D1),
+            2 => Some(Type::D2),
+            3 => Some(Type::D3),
+            _ => None,
+        }
+    }
+}
+
 /// Dimensional trait supplies methods for 3D geometry calculations
+///
+/// `Dim` is the space the coordinates live in and is checked at compile time,
+/// `dimensions` reports the same value at runtime unless a shape overrides it.
 pub trait Dimensional {
-    fn dimensions(&self) -> Type;
+    type Dim: Dimension;
+
+    fn dimensions(&self) -> Type {
+        <Self::Dim as Dimension>::TYPE
+    }
+
+    /// Length, area or volume of the shape, zero for shapes without extent
+    fn measure(&self) -> f64 {
+        0.0
+    }
+
+    /// Component-wise `(min, max)` corners enclosing the shape
+    fn bounding_box(&self) -> (PointOf<Self>, PointOf<Self>);
+
+    /// Center of mass assuming uniform density
+    fn centroid(&self) -> PointOf<Self>;
+}
+
+/// `f64` point type in the space of a [`Dimensional`] shape
+pub type PointOf<S> = <<S as Dimensional>::Dim as Dimension>::Point;
+
+impl<T: Scalar> Dimensional for Point<T> {
+    type Dim = D3;
+
+    fn bounding_box(&self) -> (Point, Point) {
+        (self.to_f64(), self.to_f64())
+    }
+
+    fn centroid(&self) -> Point {
+        self.to_f64()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn point_arithmetic_test() {
+        let a = Point::new(1.0, 2.0, 3.0);
+        let b = Point::new(4.0, 6.0, 3.0);
+        assert_eq!(b - a, Vector3::new(3.0, 4.0, 0.0));
+        assert_eq!(a + (b - a), b);
+        assert_eq!(-a, Point::new(-1.0, -2.0, -3.0));
+        assert_eq!(a * 2.0, Point::new(2.0, 4.0, 6.0));
+        assert_eq!(a.distance(&b), 5.0);
+        assert_eq!(a.lerp(&b, 0.5), Point::new(2.5, 4.0, 3.0));
+        assert_eq!(a.min(&b), a);
+        assert_eq!(Point::<f64>::origin(), Point::default());
+    }
+
+    #[test]
+    fn generic_scalar_test() {
+        let a: Point<i64> = Point::new(1, 2, 3);
+        let b = Point::new(4, 6, 3);
+        assert_eq!(a.distance_squared(&b), 25);
+        assert_eq!((b - a).cross(&Vector3::unit_z()), Vector3::new(4, -3, 0));
+        let f: Point<f32> = Point::new(0.0, 3.0, 4.0);
+        assert_eq!(f.distance(&Point::origin()), 5.0);
+        assert_eq!(a.to_f64(), Point::new(1.0, 2.0, 3.0));
+        assert_eq!(Point::from_f64(&Point::new(1.4, 2.0, 2.6)), Some(a));
+        assert_eq!(Point::<i32>::from_f64(&Point::new(1e10, 0.0, 0.0)), None);
+        assert_eq!(
+            Point::<f32>::from_f64(&Point::new(f64::NAN, 0.0, 0.0)),
+            None
+        );
+    }
 }
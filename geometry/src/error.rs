@@ -0,0 +1,84 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+use crate::crs::Crs;
+use crate::dims::Type;
+
+/// GeometryError enum list of reasons a geometric operation can fail
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GeometryError {
+    /// A coordinate is NaN or infinite
+    NonFinite,
+    /// Coordinates are too large for the scalar type to compute with
+    Overflow,
+    /// The input collapses to a lower dimension, such as collinear triangle vertices
+    Degenerate,
+    /// A value of one dimension was given where another was required
+    DimensionMismatch { expected: Type, found: Type },
+    /// Vertices or edges are connected in an invalid way
+    InvalidTopology(String),
+    /// Text could not be parsed, `line` and `column` count from one
+    Parse {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    /// Values tagged with different reference systems were combined
+    CrsMismatch { expected: Crs, found: Crs },
+    /// The reference system is not registered
+    UnknownCrs(Crs),
+    /// A UTM zone number outside `1..=60`
+    InvalidZone(u8),
+    /// The shape cannot represent its image under an affine map, such as a sheared box
+    UnsupportedTransform,
+    /// The result would need `required` elements, more than the `limit` allowed
+    LimitExceeded { required: usize, limit: usize },
+    /// Reading or writing failed
+    Io {
+        kind: io::ErrorKind,
+        message: String,
+    },
+}
+
+impl fmt::Display for GeometryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GeometryError::NonFinite => write!(f, "coordinate is not finite"),
+            GeometryError::Overflow => write!(f, "coordinate is too large for the scalar type"),
+            GeometryError::Degenerate => write!(f, "geometry is degenerate"),
+            GeometryError::DimensionMismatch { expected, found } => {
+                write!(f, "expected dimension {:?}, found {:?}", expected, found)
+            }
+            GeometryError::InvalidTopology(reason) => write!(f, "invalid topology: {}", reason),
+            GeometryError::Parse {
+                line,
+                column,
+                message,
+            } => write!(f, "parse error at {}:{}: {}", line, column, message),
+            GeometryError::CrsMismatch { expected, found } => {
+                write!(f, "expected reference system {}, found {}", expected, found)
+            }
+            GeometryError::UnknownCrs(crs) => write!(f, "unknown reference system {}", crs),
+            GeometryError::InvalidZone(zone) => write!(f, "UTM zone {} is outside 1..=60", zone),
+            GeometryError::UnsupportedTransform => {
+                write!(f, "shape cannot represent the transformed result")
+            }
+            GeometryError::LimitExceeded { required, limit } => {
+                write!(f, "{} elements required, the limit is {}", required, limit)
+            }
+            GeometryError::Io { message, .. } => write!(f, "i/o error: {}", message),
+        }
+    }
+}
+
+impl Error for GeometryError {}
+
+impl From<io::Error> for GeometryError {
+    fn from(e: io::Error) -> Self {
+        GeometryError::Io {
+            kind: e.kind(),
+            message: e.to_string(),
+        }
+    }
+}
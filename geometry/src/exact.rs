@@ -0,0 +1,588 @@
+//! Arbitrary precision rational scalar, enabled by the `exact` feature
+//!
+//! [`Rational`] implements [`Field`], so points, vectors and triangles built
+//! on it compute constructions such as circumcenters and intersections
+//! without rounding. Every finite `f64` converts exactly into a `Rational`,
+//! and [`Rational::to_f64`] rounds back to the nearest `f64` when done.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::scalar::{Field, Scalar};
+
+/// Unsigned arbitrary precision integer stored as little endian 32-bit limbs
+///
+/// Zero has no limbs and the most significant limb is never zero.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+struct Natural {
+    limbs: Vec<u32>,
+}
+
+impl Natural {
+    fn from_u64(v: u64) -> Self {
+        Natural {
+            limbs: vec![v as u32, (v >> 32) as u32],
+        }
+        .trimmed()
+    }
+
+    fn one() -> Self {
+        Natural::from_u64(1)
+    }
+
+    fn trimmed(mut self) -> Self {
+        while self.limbs.last() == Some(&0) {
+            self.limbs.pop();
+        }
+        self
+    }
+
+    fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    fn is_one(&self) -> bool {
+        self.limbs == [1]
+    }
+
+    fn bits(&self) -> u64 {
+        match self.limbs.last() {
+            Some(top) => self.limbs.len() as u64 * 32 - u64::from(top.leading_zeros()),
+            None => 0,
+        }
+    }
+
+    fn bit(&self, i: u64) -> bool {
+        let limb = (i / 32) as usize;
+        limb < self.limbs.len() && self.limbs[limb] >> (i % 32) & 1 == 1
+    }
+
+    fn trailing_zeros(&self) -> u64 {
+        let mut count = 0;
+        for &limb in &self.limbs {
+            if limb == 0 {
+                count += 32;
+            } else {
+                return count + u64::from(limb.trailing_zeros());
+            }
+        }
+        count
+    }
+
+    fn add(&self, other: &Natural) -> Natural {
+        let n = self.limbs.len().max(other.limbs.len());
+        let mut limbs = Vec::with_capacity(n + 1);
+        let mut carry = 0u64;
+        for i in 0..n {
+            let a = u64::from(*self.limbs.get(i).unwrap_or(&0));
+            let b = u64::from(*other.limbs.get(i).unwrap_or(&0));
+            let sum = a + b + carry;
+            limbs.push(sum as u32);
+            carry = sum >> 32;
+        }
+        limbs.push(carry as u32);
+        Natural { limbs }.trimmed()
+    }
+
+    /// `self - other`, requires `self >= other`
+    fn sub(&self, other: &Natural) -> Natural {
+        let mut limbs = Vec::with_capacity(self.limbs.len());
+        let mut borrow = 0i64;
+        for i in 0..self.limbs.len() {
+            let a = i64::from(self.limbs[i]);
+            let b = i64::from(*other.limbs.get(i).unwrap_or(&0));
+            let mut diff = a - b - borrow;
+            borrow = 0;
+            if diff < 0 {
+                diff += 1 << 32;
+                borrow = 1;
+            }
+            limbs.push(diff as u32);
+        }
+        debug_assert_eq!(borrow, 0, "natural subtraction underflow");
+        Natural { limbs }.trimmed()
+    }
+
+    fn mul(&self, other: &Natural) -> Natural {
+        if self.is_zero() || other.is_zero() {
+            return Natural::default();
+        }
+        let mut limbs = vec![0u32; self.limbs.len() + other.limbs.len()];
+        for (i, &a) in self.limbs.iter().enumerate() {
+            let mut carry = 0u64;
+            for (j, &b) in other.limbs.iter().enumerate() {
+                let cur = u64::from(limbs[i + j]) + u64::from(a) * u64::from(b) + carry;
+                limbs[i + j] = cur as u32;
+                carry = cur >> 32;
+            }
+            limbs[i + other.limbs.len()] = carry as u32;
+        }
+        Natural { limbs }.trimmed()
+    }
+
+    fn shl(&self, n: u64) -> Natural {
+        if self.is_zero() {
+            return Natural::default();
+        }
+        let (whole, part) = ((n / 32) as usize, (n % 32) as u32);
+        let mut limbs = vec![0u32; whole];
+        let mut carry = 0u32;
+        for &limb in &self.limbs {
+            if part == 0 {
+                limbs.push(limb);
+            } else {
+                limbs.push(limb << part | carry);
+                carry = limb >> (32 - part);
+            }
+        }
+        limbs.push(carry);
+        Natural { limbs }.trimmed()
+    }
+
+    fn shr(&self, n: u64) -> Natural {
+        let (whole, part) = ((n / 32) as usize, (n % 32) as u32);
+        if whole >= self.limbs.len() {
+            return Natural::default();
+        }
+        let src = &self.limbs[whole..];
+        let limbs = (0..src.len())
+            .map(|i| {
+                let next = *src.get(i + 1).unwrap_or(&0);
+                if part == 0 {
+                    src[i]
+                } else {
+                    src[i] >> part | next << (32 - part)
+                }
+            })
+            .collect();
+        Natural { limbs }.trimmed()
+    }
+
+    /// Quotient and remainder by binary long division, `d` must be non-zero
+    fn div_rem(&self, d: &Natural) -> (Natural, Natural) {
+        if self < d {
+            return (Natural::default(), self.clone());
+        }
+        if d.limbs.len() == 1 {
+            let d = u64::from(d.limbs[0]);
+            let mut q = vec![0u32; self.limbs.len()];
+            let mut r = 0u64;
+            for i in (0..self.limbs.len()).rev() {
+                let cur = r << 32 | u64::from(self.limbs[i]);
+                q[i] = (cur / d) as u32;
+                r = cur % d;
+            }
+            return (Natural { limbs: q }.trimmed(), Natural::from_u64(r));
+        }
+        let mut q = vec![0u32; self.limbs.len()];
+        let mut r = Natural::default();
+        for i in (0..self.bits()).rev() {
+            r = r.shl(1);
+            if self.bit(i) {
+                if r.limbs.is_empty() {
+                    r.limbs.push(1);
+                } else {
+                    r.limbs[0] |= 1;
+                }
+            }
+            if r >= *d {
+                r = r.sub(d);
+                q[(i / 32) as usize] |= 1 << (i % 32);
+            }
+        }
+        (Natural { limbs: q }.trimmed(), r)
+    }
+
+    /// Greatest common divisor by the binary algorithm
+    fn gcd(&self, other: &Natural) -> Natural {
+        if self.is_zero() {
+            return other.clone();
+        }
+        if other.is_zero() {
+            return self.clone();
+        }
+        let shift = self.trailing_zeros().min(other.trailing_zeros());
+        let mut a = self.shr(self.trailing_zeros());
+        let mut b = other.clone();
+        while !b.is_zero() {
+            b = b.shr(b.trailing_zeros());
+            if a > b {
+                std::mem::swap(&mut a, &mut b);
+            }
+            b = b.sub(&a);
+        }
+        a.shl(shift)
+    }
+
+    /// Lowest 128 bits
+    fn low_u128(&self) -> u128 {
+        self.limbs
+            .iter()
+            .take(4)
+            .enumerate()
+            .fold(0, |acc, (i, &l)| acc | u128::from(l) << (32 * i))
+    }
+}
+
+impl PartialOrd for Natural {
+    fn partial_cmp(&self, other: &Natural) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Natural {
+    fn cmp(&self, other: &Natural) -> Ordering {
+        self.limbs
+            .len()
+            .cmp(&other.limbs.len())
+            .then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()))
+    }
+}
+
+impl fmt::Display for Natural {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+        let billion = Natural::from_u64(1_000_000_000);
+        let mut chunks = Vec::new();
+        let mut n = self.clone();
+        while !n.is_zero() {
+            let (q, r) = n.div_rem(&billion);
+            chunks.push(r.low_u128());
+            n = q;
+        }
+        let mut iter = chunks.iter().rev();
+        write!(f, "{}", iter.next().unwrap())?;
+        iter.try_for_each(|c| write!(f, "{:09}", c))
+    }
+}
+
+/// Rational struct represents an exact fraction of arbitrary precision integers
+///
+/// Values are kept in lowest terms with a positive denominator, so equal
+/// rationals have equal representations.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Rational {
+    negative: bool,
+    num: Natural,
+    den: Natural,
+}
+
+impl Rational {
+    fn from_parts(negative: bool, num: Natural, den: Natural) -> Self {
+        assert!(!den.is_zero(), "rational with zero denominator");
+        let g = num.gcd(&den);
+        let (num, den) = if g.is_one() {
+            (num, den)
+        } else {
+            (num.div_rem(&g).0, den.div_rem(&g).0)
+        };
+        Rational {
+            negative: negative && !num.is_zero(),
+            num,
+            den,
+        }
+    }
+
+    /// The fraction `num / den`, panics when `den` is zero
+    pub fn new(num: i64, den: i64) -> Self {
+        Rational::from_parts(
+            (num < 0) != (den < 0),
+            Natural::from_u64(num.unsigned_abs()),
+            Natural::from_u64(den.unsigned_abs()),
+        )
+    }
+
+    /// Exact value of a finite float, `None` for NaN and infinities
+    pub fn from_f64(v: f64) -> Option<Self> {
+        if !v.is_finite() {
+            return None;
+        }
+        let bits = v.to_bits();
+        let exponent = ((bits >> 52) & 0x7ff) as i64;
+        let fraction = bits & ((1 << 52) - 1);
+        let (mantissa, exponent) = if exponent == 0 {
+            (fraction, -1074)
+        } else {
+            (fraction | 1 << 52, exponent - 1075)
+        };
+        let mantissa = Natural::from_u64(mantissa);
+        let (num, den) = if exponent >= 0 {
+            (mantissa.shl(exponent as u64), Natural::one())
+        } else {
+            (mantissa, Natural::one().shl(exponent.unsigned_abs()))
+        };
+        Some(Rational::from_parts(v < 0.0, num, den))
+    }
+
+    /// True when the value is an integer
+    pub fn is_integer(&self) -> bool {
+        self.den.is_one()
+    }
+
+    /// Multiplicative inverse, `None` for zero
+    pub fn recip(&self) -> Option<Self> {
+        if self.num.is_zero() {
+            None
+        } else {
+            Some(Rational {
+                negative: self.negative,
+                num: self.den.clone(),
+                den: self.num.clone(),
+            })
+        }
+    }
+
+    /// Nearest `f64`, rounding half to even
+    pub fn to_f64(&self) -> f64 {
+        if self.num.is_zero() {
+            return 0.0;
+        }
+        // Scale so the quotient carries at least 66 significant bits, then fold
+        // the remainder into a sticky bit so the final conversion rounds once.
+        let shift = 66 + self.den.bits() as i64 - self.num.bits() as i64;
+        let (num, den) = if shift >= 0 {
+            (self.num.shl(shift as u64), self.den.clone())
+        } else {
+            (self.num.clone(), self.den.shl(shift.unsigned_abs()))
+        };
+        let (q, r) = num.div_rem(&den);
+        let q = q.low_u128() | u128::from(!r.is_zero());
+        let magnitude = scale_by_power_of_two(q, -shift);
+        if self.negative {
+            -magnitude
+        } else {
+            magnitude
+        }
+    }
+}
+
+/// `q * 2^exp` rounded once to the nearest `f64`, including subnormal results
+fn scale_by_power_of_two(q: u128, exp: i64) -> f64 {
+    let bits = 128 - i64::from(q.leading_zeros());
+    let top = bits + exp;
+    if top > 1024 {
+        return f64::INFINITY;
+    }
+    // Precision available at this magnitude, 53 bits for normal results
+    let precision = (top + 1074).min(53);
+    if precision <= 0 {
+        let halfway = precision == 0 && q > 1 << (bits - 1);
+        return if halfway { f64::from_bits(1) } else { 0.0 };
+    }
+    let drop = bits - precision;
+    let q = if drop > 0 {
+        let kept = q >> drop;
+        let rest = q & ((1 << drop) - 1);
+        let half = 1 << (drop - 1);
+        let round_up = rest > half || (rest == half && kept & 1 == 1);
+        kept + u128::from(round_up)
+    } else {
+        q
+    };
+    let exp = exp + drop.max(0);
+    // `q` has at most 54 bits and is exact in f64, scale in two steps to avoid overflow
+    let half = exp / 2;
+    q as f64 * 2f64.powi(half as i32) * 2f64.powi((exp - half) as i32)
+}
+
+impl Default for Rational {
+    fn default() -> Self {
+        Rational::zero()
+    }
+}
+
+impl From<i64> for Rational {
+    fn from(v: i64) -> Self {
+        Rational::new(v, 1)
+    }
+}
+
+impl From<i32> for Rational {
+    fn from(v: i32) -> Self {
+        Rational::new(i64::from(v), 1)
+    }
+}
+
+impl fmt::Debug for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.negative {
+            write!(f, "-")?;
+        }
+        if self.is_integer() {
+            write!(f, "{}", self.num)
+        } else {
+            write!(f, "{}/{}", self.num, self.den)
+        }
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Rational) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Rational) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (negative, _) => {
+                let magnitude = self.num.mul(&other.den).cmp(&other.num.mul(&self.den));
+                if negative {
+                    magnitude.reverse()
+                } else {
+                    magnitude
+                }
+            }
+        }
+    }
+}
+
+impl Add for Rational {
+    type Output = Rational;
+
+    fn add(self, other: Rational) -> Rational {
+        let a = self.num.mul(&other.den);
+        let b = other.num.mul(&self.den);
+        let den = self.den.mul(&other.den);
+        if self.negative == other.negative {
+            Rational::from_parts(self.negative, a.add(&b), den)
+        } else if a >= b {
+            Rational::from_parts(self.negative, a.sub(&b), den)
+        } else {
+            Rational::from_parts(other.negative, b.sub(&a), den)
+        }
+    }
+}
+
+impl Sub for Rational {
+    type Output = Rational;
+
+    fn sub(self, other: Rational) -> Rational {
+        self + -other
+    }
+}
+
+impl Mul for Rational {
+    type Output = Rational;
+
+    fn mul(self, other: Rational) -> Rational {
+        Rational::from_parts(
+            self.negative != other.negative,
+            self.num.mul(&other.num),
+            self.den.mul(&other.den),
+        )
+    }
+}
+
+impl Div for Rational {
+    type Output = Rational;
+
+    /// Panics when dividing by zero
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, other: Rational) -> Rational {
+        self * other.recip().expect("rational division by zero")
+    }
+}
+
+impl Neg for Rational {
+    type Output = Rational;
+
+    fn neg(self) -> Rational {
+        Rational {
+            negative: !self.negative && !self.num.is_zero(),
+            ..self
+        }
+    }
+}
+
+impl Scalar for Rational {
+    fn zero() -> Self {
+        Rational {
+            negative: false,
+            num: Natural::default(),
+            den: Natural::one(),
+        }
+    }
+
+    fn one() -> Self {
+        Rational::new(1, 1)
+    }
+
+    fn from_i32(v: i32) -> Self {
+        Rational::from(v)
+    }
+
+    fn to_f64(&self) -> f64 {
+        Rational::to_f64(self)
+    }
+
+    fn try_from_f64(v: f64) -> Option<Self> {
+        Rational::from_f64(v)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.num.is_zero()
+    }
+}
+
+impl Field for Rational {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dims::Point;
+
+    #[test]
+    fn rational_arithmetic_test() {
+        let third = Rational::new(1, 3);
+        let sum = third.clone() + third.clone() + third.clone();
+        assert_eq!(sum, Rational::one());
+        assert_eq!(Rational::new(6, -4), Rational::new(-3, 2));
+        assert_eq!(Rational::new(-3, 2).to_string(), "-3/2");
+        assert!(Rational::new(-1, 2) < Rational::new(1, 3));
+        assert_eq!(third.clone() / Rational::new(2, 3), Rational::new(1, 2));
+        assert_eq!(third.to_f64(), 1.0 / 3.0);
+        let big = (0..8).fold(Rational::from(1_000_000_007), |acc, _| acc.clone() * acc);
+        assert_eq!(big.clone() / big.clone(), Rational::one());
+        assert_eq!((big.clone() - big).to_f64(), 0.0);
+    }
+
+    #[test]
+    fn float_roundtrip_test() {
+        for &v in &[
+            0.1,
+            -2.5e-310,
+            1e300,
+            f64::MAX,
+            f64::MIN_POSITIVE,
+            5e-324,
+            -0.0,
+        ] {
+            assert_eq!(Rational::from_f64(v).unwrap().to_f64(), v);
+        }
+        assert_eq!(Rational::from_f64(f64::NAN), None);
+        let tenth = Rational::from_f64(0.1).unwrap();
+        assert_ne!(tenth, Rational::new(1, 10));
+        assert_eq!(Rational::new(1, 10).to_f64(), 0.1);
+    }
+
+    #[test]
+    fn exact_point_test() {
+        let p = Point::from_f64(&Point::new(0.1, 0.2, 0.3)).unwrap();
+        let q = Point::from_f64(&Point::new(0.3, 0.6, 0.9)).unwrap();
+        let mid = p.lerp(&q, Rational::new(1, 2));
+        assert_eq!(mid.to_f64(), Point::new(0.2, 0.4, 0.6));
+        assert!(!Rational::collinear(&p, &mid, &Point::origin()));
+    }
+}
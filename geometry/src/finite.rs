@@ -0,0 +1,129 @@
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::hash::{Hash, Hasher};
+
+use crate::dimension::D3;
+use crate::dims::{Dimensional, Point};
+use crate::error::GeometryError;
+
+/// FinitePoint struct represents an `f64` point whose coordinates are all finite
+///
+/// Negative zero is stored as positive zero, so points that compare equal
+/// also hash equal. Points are ordered lexicographically by `x`, `y`, `z`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct FinitePoint(Point);
+
+impl FinitePoint {
+    /// Creates a point, failing when a coordinate is NaN or infinite
+    pub fn new(x: f64, y: f64, z: f64) -> Result<Self, GeometryError> {
+        if x.is_finite() && y.is_finite() && z.is_finite() {
+            // Adding zero turns -0.0 into 0.0 and leaves every other value unchanged
+            Ok(FinitePoint(Point::new(x + 0.0, y + 0.0, z + 0.0)))
+        } else {
+            Err(GeometryError::NonFinite)
+        }
+    }
+
+    /// X coordinate
+    pub fn x(&self) -> f64 {
+        self.0.x()
+    }
+
+    /// Y coordinate
+    pub fn y(&self) -> f64 {
+        self.0.y()
+    }
+
+    /// Z coordinate
+    pub fn z(&self) -> f64 {
+        self.0.z()
+    }
+
+    /// The validated point
+    pub fn as_point(&self) -> &Point {
+        &self.0
+    }
+}
+
+impl TryFrom<Point> for FinitePoint {
+    type Error = GeometryError;
+
+    fn try_from(p: Point) -> Result<Self, GeometryError> {
+        FinitePoint::new(p.x(), p.y(), p.z())
+    }
+}
+
+impl From<FinitePoint> for Point {
+    fn from(p: FinitePoint) -> Self {
+        p.0
+    }
+}
+
+impl Eq for FinitePoint {}
+
+impl PartialOrd for FinitePoint {
+    fn partial_cmp(&self, other: &FinitePoint) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for FinitePoint {
+    fn cmp(&self, other: &FinitePoint) -> Ordering {
+        self.x()
+            .total_cmp(&other.x())
+            .then_with(|| self.y().total_cmp(&other.y()))
+            .then_with(|| self.z().total_cmp(&other.z()))
+    }
+}
+
+impl Hash for FinitePoint {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for v in self.0.to_array() {
+            v.to_bits().hash(state);
+        }
+    }
+}
+
+impl Dimensional for FinitePoint {
+    type Dim = D3;
+
+    fn bounding_box(&self) -> (Point, Point) {
+        (self.0, self.0)
+    }
+
+    fn centroid(&self) -> Point {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::{BTreeSet, HashSet};
+
+    #[test]
+    fn validation_test() {
+        assert_eq!(
+            FinitePoint::new(f64::NAN, 0.0, 0.0),
+            Err(GeometryError::NonFinite)
+        );
+        let inf = Point::new(0.0, f64::INFINITY, 0.0);
+        assert_eq!(FinitePoint::try_from(inf), Err(GeometryError::NonFinite));
+        let p = FinitePoint::new(1.0, 2.0, 3.0).unwrap();
+        assert_eq!(Point::from(p), Point::new(1.0, 2.0, 3.0));
+    }
+
+    #[test]
+    fn map_key_test() {
+        let points = [
+            FinitePoint::new(1.0, 0.0, 0.0).unwrap(),
+            FinitePoint::new(0.0, -0.0, 0.0).unwrap(),
+            FinitePoint::new(-1.0, 5.0, 0.0).unwrap(),
+            FinitePoint::new(0.0, 0.0, 0.0).unwrap(),
+        ];
+        let unique: HashSet<_> = points.iter().collect();
+        assert_eq!(unique.len(), 3);
+        let sorted: Vec<_> = points.iter().collect::<BTreeSet<_>>().into_iter().collect();
+        assert_eq!(sorted, [&points[2], &points[1], &points[0]]);
+    }
+}
@@ -0,0 +1,422 @@
+//! Deterministic Q32.32 fixed-point scalar
+//!
+//! [`Fixed`] stores values as a 64-bit integer count of `2^-32` steps and
+//! implements every operation, including square roots and trigonometry, with
+//! integer arithmetic only. Results are therefore bit-identical on every
+//! platform, compiler and optimization level. Overflow saturates instead of
+//! wrapping or panicking, and division by zero saturates towards the sign of
+//! the dividend.
+
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+use std::sync::OnceLock;
+
+use crate::dims::Point;
+use crate::predicates;
+use crate::scalar::{Field, Real, Scalar};
+
+/// Fractional bits of the public representation
+const FRAC_BITS: u32 = 32;
+/// Fractional bits used inside CORDIC iterations
+const CORDIC_BITS: u32 = 60;
+/// Pi with 32 fractional bits
+const PI_RAW: i64 = 13_493_037_705;
+/// Pi with 60 fractional bits
+const PI_CORDIC: i128 = 3_622_009_729_038_561_421;
+/// Reciprocal of the CORDIC gain with 60 fractional bits
+const CORDIC_K: i128 = 700_114_967_507_363_238;
+const CORDIC_STEPS: usize = 61;
+
+/// Fixed struct represents a signed Q32.32 fixed-point number
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Fixed(i64);
+
+/// `atan(2^-i)` with 60 fractional bits, built from integer series on first use
+fn atan_table() -> &'static [i128; CORDIC_STEPS] {
+    static TABLE: OnceLock<[i128; CORDIC_STEPS]> = OnceLock::new();
+    TABLE.get_or_init(|| {
+        let mut table = [0i128; CORDIC_STEPS];
+        table[0] = PI_CORDIC / 4;
+        for (i, entry) in table.iter_mut().enumerate().skip(1) {
+            let mut sum = 0i128;
+            let mut k = 0u32;
+            loop {
+                let shift = i as u32 * (2 * k + 1);
+                if shift > CORDIC_BITS {
+                    break;
+                }
+                let term = (1i128 << (CORDIC_BITS - shift)) / i128::from(2 * k + 1);
+                sum += if k & 1 == 0 { term } else { -term };
+                k += 1;
+            }
+            *entry = sum;
+        }
+        table
+    })
+}
+
+fn saturate(v: i128) -> i64 {
+    v.clamp(i128::from(i64::MIN), i128::from(i64::MAX)) as i64
+}
+
+/// Right shift rounding half away from zero
+fn round_shift(v: i128, bits: u32) -> i128 {
+    let half = 1i128 << (bits - 1);
+    if v >= 0 {
+        (v + half) >> bits
+    } else {
+        -((-v + half) >> bits)
+    }
+}
+
+/// Integer square root, the largest `r` with `r * r <= n`
+fn isqrt(n: u128) -> u128 {
+    if n < 2 {
+        return n;
+    }
+    let mut bit = 1u128 << ((127 - n.leading_zeros()) & !1);
+    let mut n = n;
+    let mut r = 0u128;
+    while bit != 0 {
+        if n >= r + bit {
+            n -= r + bit;
+            r = (r >> 1) + bit;
+        } else {
+            r >>= 1;
+        }
+        bit >>= 2;
+    }
+    r
+}
+
+/// CORDIC rotation of `(1, 0)` by `angle` in `[-pi/2, pi/2]`, returns `(cos, sin)`
+fn cordic_rotate(angle: i128) -> (i128, i128) {
+    let table = atan_table();
+    let (mut x, mut y, mut z) = (CORDIC_K, 0i128, angle);
+    for (i, &step) in table.iter().enumerate() {
+        let (dx, dy) = (y >> i, x >> i);
+        if z >= 0 {
+            x -= dx;
+            y += dy;
+            z -= step;
+        } else {
+            x += dx;
+            y -= dy;
+            z += step;
+        }
+    }
+    (x, y)
+}
+
+/// CORDIC vectoring of `(x, y)` with `x > 0`, returns `atan(y / x)` in CORDIC units
+fn cordic_vector(mut x: i128, mut y: i128) -> i128 {
+    // Scale into 2^59..2^60 so the gain of about 1.65 cannot overflow and
+    // small inputs keep full angular resolution
+    let top = 127 - (x.abs().max(y.abs()) as u128).leading_zeros() as i32;
+    let shift = 59 - top;
+    if shift > 0 {
+        x <<= shift;
+        y <<= shift;
+    } else {
+        x >>= -shift;
+        y >>= -shift;
+    }
+    let mut z = 0i128;
+    for (i, &step) in atan_table().iter().enumerate() {
+        let (dx, dy) = (y >> i, x >> i);
+        if y > 0 {
+            x += dx;
+            y -= dy;
+            z += step;
+        } else {
+            x -= dx;
+            y += dy;
+            z -= step;
+        }
+    }
+    z
+}
+
+impl Fixed {
+    /// Smallest positive value, `2^-32`
+    pub const EPSILON: Fixed = Fixed(1);
+    /// Largest representable value
+    pub const MAX: Fixed = Fixed(i64::MAX);
+    /// Smallest representable value
+    pub const MIN: Fixed = Fixed(i64::MIN);
+    /// Closest value to pi
+    pub const PI: Fixed = Fixed(PI_RAW);
+
+    /// Value from its raw representation, `raw * 2^-32`
+    pub const fn from_raw(raw: i64) -> Self {
+        Fixed(raw)
+    }
+
+    /// Raw representation, `self * 2^32`
+    pub const fn to_raw(self) -> i64 {
+        self.0
+    }
+
+    /// Nearest fixed-point value, saturating out of range values and mapping NaN to zero
+    pub fn from_f64(v: f64) -> Self {
+        Fixed((v * (1u64 << FRAC_BITS) as f64).round() as i64)
+    }
+
+    /// Value as a float, exact whenever it fits in 53 bits
+    pub fn to_f64(self) -> f64 {
+        self.0 as f64 / (1u64 << FRAC_BITS) as f64
+    }
+
+    /// Reduces an angle to `[-pi, pi]` in CORDIC units
+    fn reduced_angle(self) -> i128 {
+        let two_pi = 2 * i128::from(PI_RAW);
+        let mut r = i128::from(self.0) % two_pi;
+        if r > i128::from(PI_RAW) {
+            r -= two_pi;
+        } else if r < -i128::from(PI_RAW) {
+            r += two_pi;
+        }
+        r << (CORDIC_BITS - FRAC_BITS)
+    }
+
+    /// Cosine and sine together
+    pub fn cos_sin(self) -> (Fixed, Fixed) {
+        let half_pi = PI_CORDIC / 2;
+        let r = self.reduced_angle();
+        let (c, s) = if r > half_pi {
+            let (c, s) = cordic_rotate(PI_CORDIC - r);
+            (-c, s)
+        } else if r < -half_pi {
+            let (c, s) = cordic_rotate(-PI_CORDIC - r);
+            (-c, s)
+        } else {
+            cordic_rotate(r)
+        };
+        let bits = CORDIC_BITS - FRAC_BITS;
+        (
+            Fixed(saturate(round_shift(c, bits))),
+            Fixed(saturate(round_shift(s, bits))),
+        )
+    }
+}
+
+impl fmt::Display for Fixed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_f64())
+    }
+}
+
+impl From<i32> for Fixed {
+    fn from(v: i32) -> Self {
+        Fixed(i64::from(v) << FRAC_BITS)
+    }
+}
+
+impl Add for Fixed {
+    type Output = Fixed;
+
+    fn add(self, other: Fixed) -> Fixed {
+        Fixed(self.0.saturating_add(other.0))
+    }
+}
+
+impl Sub for Fixed {
+    type Output = Fixed;
+
+    fn sub(self, other: Fixed) -> Fixed {
+        Fixed(self.0.saturating_sub(other.0))
+    }
+}
+
+impl Mul for Fixed {
+    type Output = Fixed;
+
+    fn mul(self, other: Fixed) -> Fixed {
+        let product = i128::from(self.0) * i128::from(other.0);
+        Fixed(saturate(round_shift(product, FRAC_BITS)))
+    }
+}
+
+impl Div for Fixed {
+    type Output = Fixed;
+
+    /// Rounds towards zero, dividing by zero saturates
+    fn div(self, other: Fixed) -> Fixed {
+        if other.0 == 0 {
+            return match self.0.signum() {
+                1 => Fixed::MAX,
+                -1 => Fixed::MIN,
+                _ => Fixed(0),
+            };
+        }
+        Fixed(saturate(
+            (i128::from(self.0) << FRAC_BITS) / i128::from(other.0),
+        ))
+    }
+}
+
+impl Neg for Fixed {
+    type Output = Fixed;
+
+    fn neg(self) -> Fixed {
+        Fixed(self.0.saturating_neg())
+    }
+}
+
+impl Scalar for Fixed {
+    fn zero() -> Self {
+        Fixed(0)
+    }
+
+    fn one() -> Self {
+        Fixed(1 << FRAC_BITS)
+    }
+
+    fn from_i32(v: i32) -> Self {
+        Fixed::from(v)
+    }
+
+    fn to_f64(&self) -> f64 {
+        Fixed::to_f64(*self)
+    }
+
+    fn try_from_f64(v: f64) -> Option<Self> {
+        let raw = (v * (1u64 << FRAC_BITS) as f64).round();
+        let bound = -(i64::MIN as f64);
+        (-bound..bound).contains(&raw).then_some(Fixed(raw as i64))
+    }
+
+    /// Decided on the raw values, so saturation cannot fake collinearity
+    fn collinear(a: &Point<Self>, b: &Point<Self>, c: &Point<Self>) -> bool {
+        let raw = |p: &Point<Self>| p.to_array().map(Fixed::to_raw);
+        predicates::collinear_int(raw(a), raw(b), raw(c))
+    }
+}
+
+impl Field for Fixed {}
+
+impl Real for Fixed {
+    fn from_f64(v: f64) -> Self {
+        Fixed::from_f64(v)
+    }
+
+    fn pi() -> Self {
+        Fixed::PI
+    }
+
+    /// Square root rounded down, zero for negative input
+    fn sqrt(&self) -> Self {
+        if self.0 <= 0 {
+            return Fixed(0);
+        }
+        Fixed(isqrt((self.0 as u128) << FRAC_BITS) as i64)
+    }
+
+    fn sin(&self) -> Self {
+        self.cos_sin().1
+    }
+
+    fn cos(&self) -> Self {
+        self.cos_sin().0
+    }
+
+    fn tan(&self) -> Self {
+        let (c, s) = self.cos_sin();
+        s / c
+    }
+
+    /// Input is clamped to `[-1, 1]`
+    fn asin(&self) -> Self {
+        let x = (*self).max(-Fixed::one()).min(Fixed::one());
+        x.atan2(&(Fixed::one() - x * x).sqrt())
+    }
+
+    /// Input is clamped to `[-1, 1]`
+    fn acos(&self) -> Self {
+        let x = (*self).max(-Fixed::one()).min(Fixed::one());
+        (Fixed::one() - x * x).sqrt().atan2(&x)
+    }
+
+    fn atan2(&self, x: &Self) -> Self {
+        let (y, x) = (i128::from(self.0), i128::from(x.0));
+        let angle = if x == 0 && y == 0 {
+            0
+        } else if x > 0 {
+            cordic_vector(x, y)
+        } else if y >= 0 {
+            PI_CORDIC + cordic_vector(-x, -y)
+        } else {
+            -PI_CORDIC + cordic_vector(-x, -y)
+        };
+        Fixed(saturate(round_shift(angle, CORDIC_BITS - FRAC_BITS)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: Fixed, b: f64, tolerance: f64) -> bool {
+        (a.to_f64() - b).abs() <= tolerance
+    }
+
+    #[test]
+    fn arithmetic_test() {
+        let half = Fixed::from_f64(0.5);
+        assert_eq!(half + half, Fixed::one());
+        assert_eq!(Fixed::from(3) * half, Fixed::from_f64(1.5));
+        assert_eq!(Fixed::one() / Fixed::from(4), Fixed::from_f64(0.25));
+        assert_eq!(Fixed::MAX + Fixed::one(), Fixed::MAX);
+        assert_eq!(Fixed::one() / Fixed::zero(), Fixed::MAX);
+        assert_eq!(Fixed::try_from_f64(0.5), Some(half));
+        assert_eq!(Fixed::try_from_f64(3e9), None);
+        assert_eq!(Fixed::from(9).sqrt(), Fixed::from(3));
+        assert!(close(Fixed::from(2).sqrt(), std::f64::consts::SQRT_2, 1e-9));
+    }
+
+    #[test]
+    fn trigonometry_test() {
+        for i in -40..=40 {
+            let v = f64::from(i) * 0.2;
+            let x = Fixed::from_f64(v);
+            assert!(close(x.sin(), x.to_f64().sin(), 1e-8), "sin {}", v);
+            assert!(close(x.cos(), x.to_f64().cos(), 1e-8), "cos {}", v);
+            let y = Fixed::from_f64(v.sin() * 3.0);
+            let w = Fixed::from_f64(v.cos() * 3.0);
+            assert!(
+                close(y.atan2(&w), y.to_f64().atan2(w.to_f64()), 1e-8),
+                "atan2 {}",
+                v
+            );
+        }
+        assert!(close(Fixed::from_f64(0.5).asin(), 0.5_f64.asin(), 1e-8));
+        assert!(close(Fixed::from_f64(-0.3).acos(), (-0.3_f64).acos(), 1e-8));
+        assert!(close(Fixed::from_f64(1.0).tan(), 1.0_f64.tan(), 1e-8));
+    }
+
+    #[test]
+    fn collinear_test() {
+        let p = |x: i32, y: i32| Point::new(Fixed::from(x), Fixed::from(y), Fixed::zero());
+        // Both cross product terms saturate, yet the points are not collinear
+        assert!(!Fixed::collinear(
+            &p(0, 0),
+            &p(100_000, 100_001),
+            &p(100_001, 100_000)
+        ));
+        assert!(Fixed::collinear(
+            &p(0, 0),
+            &p(100_000, 100_000),
+            &p(-100_000, -100_000)
+        ));
+    }
+
+    #[test]
+    fn deterministic_point_test() {
+        let a = Point::<Fixed>::from_f64(&Point::new(1.5, -2.25, 0.125)).unwrap();
+        let b = Point::<Fixed>::from_f64(&Point::new(-0.5, 4.0, 2.0)).unwrap();
+        let d = a.distance(&b);
+        assert!(close(d, a.to_f64().distance(&b.to_f64()), 1e-8));
+        // floor(sqrt(46.578125) * 2^32), the same on every platform
+        assert_eq!(d.to_raw(), 29_312_365_160);
+    }
+}
@@ -0,0 +1,276 @@
+//! Geodetic coordinates on a reference ellipsoid
+//!
+//! Latitude and longitude are in degrees and altitude in meters above the
+//! ellipsoid. Earth-centered, earth-fixed (ECEF) positions and local
+//! East-North-Up (ENU) positions are ordinary [`Point`]s in meters.
+
+use crate::dims::Point;
+use crate::matrix::Matrix3;
+use crate::transform::Affine3;
+use crate::vector::Vector3;
+
+/// Ellipsoid struct represents a reference ellipsoid of revolution
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ellipsoid {
+    semi_major: f64,
+    flattening: f64,
+}
+
+impl Ellipsoid {
+    /// World Geodetic System 1984, used by GPS
+    pub const WGS84: Ellipsoid = Ellipsoid {
+        semi_major: 6_378_137.0,
+        flattening: 1.0 / 298.257_223_563,
+    };
+
+    /// Geodetic Reference System 1980
+    pub const GRS80: Ellipsoid = Ellipsoid {
+        semi_major: 6_378_137.0,
+        flattening: 1.0 / 298.257_222_101,
+    };
+
+    /// Creates an ellipsoid from its equatorial radius in meters and flattening
+    pub fn new(semi_major: f64, flattening: f64) -> Self {
+        Ellipsoid {
+            semi_major,
+            flattening,
+        }
+    }
+
+    /// Equatorial radius in meters
+    pub fn semi_major(&self) -> f64 {
+        self.semi_major
+    }
+
+    /// Polar radius in meters
+    pub fn semi_minor(&self) -> f64 {
+        self.semi_major * (1.0 - self.flattening)
+    }
+
+    /// Flattening, `(a - b) / a`
+    pub fn flattening(&self) -> f64 {
+        self.flattening
+    }
+
+    /// Square of the first eccentricity
+    pub fn eccentricity_squared(&self) -> f64 {
+        self.flattening * (2.0 - self.flattening)
+    }
+
+    /// Radius of curvature in the prime vertical at a latitude in radians
+    fn prime_vertical_radius(&self, sin_lat: f64) -> f64 {
+        self.semi_major / (1.0 - self.eccentricity_squared() * sin_lat * sin_lat).sqrt()
+    }
+
+    /// Earth-centered, earth-fixed position of a geodetic coordinate
+    pub fn to_ecef(&self, g: &Geodetic) -> Point {
+        let (sin_lat, cos_lat) = g.latitude.to_radians().sin_cos();
+        let (sin_lon, cos_lon) = g.longitude.to_radians().sin_cos();
+        let n = self.prime_vertical_radius(sin_lat);
+        let r = (n + g.altitude) * cos_lat;
+        Point::new(
+            r * cos_lon,
+            r * sin_lon,
+            (n * (1.0 - self.eccentricity_squared()) + g.altitude) * sin_lat,
+        )
+    }
+
+    /// Geodetic coordinate of an earth-centered, earth-fixed position
+    ///
+    /// Iterates the latitude to convergence, which takes a handful of steps for
+    /// points near the surface. Longitude is zero on the polar axis.
+    pub fn to_geodetic(&self, p: &Point) -> Geodetic {
+        let e2 = self.eccentricity_squared();
+        let rho = p.x().hypot(p.y());
+        let longitude = if rho == 0.0 { 0.0 } else { p.y().atan2(p.x()) };
+        let mut lat = p.z().atan2(rho * (1.0 - e2));
+        for _ in 0..10 {
+            let sin_lat = lat.sin();
+            let next = (p.z() + e2 * self.prime_vertical_radius(sin_lat) * sin_lat).atan2(rho);
+            let done = (next - lat).abs() < 1e-15;
+            lat = next;
+            if done {
+                break;
+            }
+        }
+        let (sin_lat, cos_lat) = lat.sin_cos();
+        let altitude = rho * cos_lat + p.z() * sin_lat
+            - self.semi_major * (1.0 - e2 * sin_lat * sin_lat).sqrt();
+        Geodetic::new(lat.to_degrees(), longitude.to_degrees(), altitude)
+    }
+}
+
+impl Default for Ellipsoid {
+    fn default() -> Self {
+        Ellipsoid::WGS84
+    }
+}
+
+/// Geodetic struct represents a latitude, longitude and altitude
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Geodetic {
+    latitude: f64,
+    longitude: f64,
+    altitude: f64,
+}
+
+impl Geodetic {
+    /// Creates a coordinate from latitude and longitude in degrees and altitude in meters
+    pub fn new(latitude: f64, longitude: f64, altitude: f64) -> Self {
+        Geodetic {
+            latitude,
+            longitude,
+            altitude,
+        }
+    }
+
+    /// Latitude in degrees, positive north
+    pub fn latitude(&self) -> f64 {
+        self.latitude
+    }
+
+    /// Longitude in degrees, positive east
+    pub fn longitude(&self) -> f64 {
+        self.longitude
+    }
+
+    /// Height above the ellipsoid in meters
+    pub fn altitude(&self) -> f64 {
+        self.altitude
+    }
+
+    /// Earth-centered, earth-fixed position on WGS84
+    pub fn to_ecef(&self) -> Point {
+        Ellipsoid::WGS84.to_ecef(self)
+    }
+
+    /// Geodetic coordinate of an earth-centered, earth-fixed position on WGS84
+    pub fn from_ecef(p: &Point) -> Self {
+        Ellipsoid::WGS84.to_geodetic(p)
+    }
+}
+
+/// EnuFrame struct represents a local East-North-Up tangent frame anchored at a reference
+///
+/// X points east, y north and z up along the ellipsoid normal, all in meters
+/// from the reference position.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EnuFrame {
+    reference: Geodetic,
+    ellipsoid: Ellipsoid,
+    from_ecef: Affine3,
+}
+
+impl EnuFrame {
+    /// Frame anchored at `reference` on WGS84
+    pub fn new(reference: Geodetic) -> Self {
+        EnuFrame::with_ellipsoid(reference, Ellipsoid::WGS84)
+    }
+
+    /// Frame anchored at `reference` on the given ellipsoid
+    pub fn with_ellipsoid(reference: Geodetic, ellipsoid: Ellipsoid) -> Self {
+        let (sin_lat, cos_lat) = reference.latitude.to_radians().sin_cos();
+        let (sin_lon, cos_lon) = reference.longitude.to_radians().sin_cos();
+        let rotation = Matrix3::new([
+            [-sin_lon, cos_lon, 0.0],
+            [-sin_lat * cos_lon, -sin_lat * sin_lon, cos_lat],
+            [cos_lat * cos_lon, cos_lat * sin_lon, sin_lat],
+        ]);
+        let origin = ellipsoid.to_ecef(&reference).to_vector();
+        let from_ecef = Affine3::new(rotation, -(rotation * origin));
+        EnuFrame {
+            reference,
+            ellipsoid,
+            from_ecef,
+        }
+    }
+
+    /// Geodetic position of the frame origin
+    pub fn reference(&self) -> Geodetic {
+        self.reference
+    }
+
+    /// Ellipsoid the frame is defined on
+    pub fn ellipsoid(&self) -> Ellipsoid {
+        self.ellipsoid
+    }
+
+    /// Rigid transform from earth-centered, earth-fixed to local coordinates
+    pub fn ecef_to_enu_transform(&self) -> Affine3 {
+        self.from_ecef
+    }
+
+    /// Local position of an earth-centered, earth-fixed point
+    pub fn ecef_to_enu(&self, p: &Point) -> Point {
+        self.from_ecef.apply_point(p)
+    }
+
+    /// Earth-centered, earth-fixed position of a local point
+    pub fn enu_to_ecef(&self, p: &Point) -> Point {
+        let rotation = self.from_ecef.linear().transpose();
+        let offset = *p - Point::from(self.from_ecef.translation_part());
+        Point::from(rotation * offset)
+    }
+
+    /// Local position of a geodetic coordinate
+    pub fn to_enu(&self, g: &Geodetic) -> Point {
+        self.ecef_to_enu(&self.ellipsoid.to_ecef(g))
+    }
+
+    /// Geodetic coordinate of a local point
+    pub fn to_geodetic(&self, p: &Point) -> Geodetic {
+        self.ellipsoid.to_geodetic(&self.enu_to_ecef(p))
+    }
+
+    /// Unit vector pointing up at the reference
+    pub fn up(&self) -> Vector3 {
+        let rows = self.from_ecef.linear().rows();
+        Vector3::from(rows[2])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::approx::ApproxEq;
+
+    #[test]
+    fn ecef_test() {
+        let equator = Geodetic::new(0.0, 0.0, 0.0).to_ecef();
+        assert!(equator.abs_diff_eq(&Point::new(6_378_137.0, 0.0, 0.0), 1e-9));
+        let pole = Geodetic::new(90.0, 0.0, 0.0).to_ecef();
+        assert!((pole.z() - 6_356_752.314_245).abs() < 1e-6);
+        for &(lat, lon, alt) in &[
+            (47.3769, 8.5417, 408.0),
+            (-33.8688, 151.2093, 0.0),
+            (89.999, -120.0, 1e5),
+        ] {
+            let g = Geodetic::from_ecef(&Geodetic::new(lat, lon, alt).to_ecef());
+            assert!((g.latitude() - lat).abs() < 1e-11);
+            assert!((g.longitude() - lon).abs() < 1e-11);
+            assert!((g.altitude() - alt).abs() < 1e-6);
+        }
+        let g = Geodetic::from_ecef(&Point::new(0.0, 0.0, -6_356_752.314_245));
+        assert_eq!((g.latitude(), g.longitude()), (-90.0, 0.0));
+        assert!(g.altitude().abs() < 1e-6);
+    }
+
+    #[test]
+    fn enu_test() {
+        let frame = EnuFrame::new(Geodetic::new(45.0, 7.0, 200.0));
+        assert!(frame
+            .to_enu(&frame.reference())
+            .abs_diff_eq(&Point::origin(), 1e-8));
+        let north = frame.to_enu(&Geodetic::new(45.001, 7.0, 200.0));
+        assert!(north.y() > 110.0 && north.y() < 112.0 && north.x().abs() < 1e-6);
+        let east = frame.to_enu(&Geodetic::new(45.0, 7.001, 200.0));
+        assert!(east.x() > 78.0 && east.x() < 80.0);
+        assert!(frame.to_enu(&Geodetic::new(45.0, 7.0, 210.0)).z() > 9.99);
+        let p = Point::new(120.0, -35.0, 4.0);
+        assert!(frame
+            .ecef_to_enu(&frame.enu_to_ecef(&p))
+            .abs_diff_eq(&p, 1e-8));
+        let g = frame.to_geodetic(&Point::new(0.0, 0.0, 10.0));
+        assert!((g.altitude() - 210.0).abs() < 1e-6);
+    }
+}
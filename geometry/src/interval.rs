@@ -0,0 +1,541 @@
+//! Interval arithmetic scalar with outward rounding
+//!
+//! An [`Interval`] encloses every real value a computation could have produced
+//! from its inputs. Arithmetic operations use error-free transformations to
+//! round the lower bound down and the upper bound up only when the floating
+//! point result is inexact, so exact inputs keep tight bounds. Transcendental
+//! functions rely on the platform `libm` and widen their results by two ulps.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::dims::Point;
+use crate::scalar::{Field, Real, Scalar};
+
+/// Interval struct represents a closed range `[lo, hi]` certainly containing a real value
+///
+/// Ordering comparisons are certain: `a < b` only when every value of `a` is
+/// below every value of `b`, and overlapping intervals are unordered.
+/// Equality is equality of the ranges.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Interval {
+    lo: f64,
+    hi: f64,
+}
+
+/// Rounding slack for library transcendental functions, in ulps
+const LIBM_ULPS: u32 = 2;
+
+/// Smallest float above `v`, NaN and positive infinity are returned unchanged
+fn next_up(v: f64) -> f64 {
+    if v.is_nan() || v == f64::INFINITY {
+        v
+    } else if v == 0.0 {
+        f64::from_bits(1)
+    } else if v > 0.0 {
+        f64::from_bits(v.to_bits() + 1)
+    } else {
+        f64::from_bits(v.to_bits() - 1)
+    }
+}
+
+/// Largest float below `v`, NaN and negative infinity are returned unchanged
+fn next_down(v: f64) -> f64 {
+    -next_up(-v)
+}
+
+fn down(v: f64, ulps: u32) -> f64 {
+    (0..ulps).fold(v, |v, _| next_down(v))
+}
+
+fn up(v: f64, ulps: u32) -> f64 {
+    (0..ulps).fold(v, |v, _| next_up(v))
+}
+
+/// Rounded value and the sign of the rounding error `exact - rounded`
+fn sum_with_error(a: f64, b: f64) -> (f64, f64) {
+    let s = a + b;
+    let bv = s - a;
+    let av = s - bv;
+    (s, (a - av) + (b - bv))
+}
+
+fn product_with_error(a: f64, b: f64) -> (f64, f64) {
+    let p = a * b;
+    (p, a.mul_add(b, -p))
+}
+
+/// Lower bound of an exact value known as `rounded + error`
+fn lower(rounded: f64, error: f64) -> f64 {
+    if error < 0.0 || error.is_nan() {
+        next_down(rounded)
+    } else {
+        rounded
+    }
+}
+
+/// Upper bound of an exact value known as `rounded + error`
+fn upper(rounded: f64, error: f64) -> f64 {
+    if error > 0.0 || error.is_nan() {
+        next_up(rounded)
+    } else {
+        rounded
+    }
+}
+
+/// Zero times an infinite bound is zero, the bound only stands for large reals
+fn product_bounds(a: f64, b: f64) -> (f64, f64) {
+    if a == 0.0 || b == 0.0 {
+        return (0.0, 0.0);
+    }
+    let (p, e) = product_with_error(a, b);
+    (lower(p, e), upper(p, e))
+}
+
+fn quotient_bounds(a: f64, b: f64) -> (f64, f64) {
+    let q = a / b;
+    if !q.is_finite() || q == 0.0 {
+        return (down(q, 1), up(q, 1));
+    }
+    // a - q * b is exact, its sign against b's tells which way q was rounded
+    let residual = (-q).mul_add(b, a);
+    let error = if b > 0.0 { residual } else { -residual };
+    (lower(q, error), upper(q, error))
+}
+
+impl Interval {
+    /// Creates the interval `[lo, hi]`, panics when `lo > hi` or either bound is NaN
+    pub fn new(lo: f64, hi: f64) -> Self {
+        assert!(lo <= hi, "interval lower bound above upper bound");
+        Interval { lo, hi }
+    }
+
+    /// Interval holding exactly one value
+    pub fn point(v: f64) -> Self {
+        Interval::new(v, v)
+    }
+
+    /// The whole real line
+    pub fn entire() -> Self {
+        Interval::new(f64::NEG_INFINITY, f64::INFINITY)
+    }
+
+    /// Lower bound
+    pub fn lo(&self) -> f64 {
+        self.lo
+    }
+
+    /// Upper bound
+    pub fn hi(&self) -> f64 {
+        self.hi
+    }
+
+    /// Width of the interval, an upper bound on the error of its midpoint
+    pub fn width(&self) -> f64 {
+        up(self.hi - self.lo, 1)
+    }
+
+    /// Value halfway between the bounds
+    pub fn midpoint(&self) -> f64 {
+        if self.lo == self.hi {
+            self.lo
+        } else {
+            self.lo / 2.0 + self.hi / 2.0
+        }
+    }
+
+    /// True when `v` lies in the interval
+    pub fn contains(&self, v: f64) -> bool {
+        self.lo <= v && v <= self.hi
+    }
+
+    /// Smallest interval containing both intervals
+    pub fn hull(&self, other: &Interval) -> Interval {
+        Interval::new(self.lo.min(other.lo), self.hi.max(other.hi))
+    }
+
+    /// Common part of both intervals, `None` when they are disjoint
+    pub fn intersection(&self, other: &Interval) -> Option<Interval> {
+        let lo = self.lo.max(other.lo);
+        let hi = self.hi.min(other.hi);
+        if lo <= hi {
+            Some(Interval::new(lo, hi))
+        } else {
+            None
+        }
+    }
+
+    /// Interval from computed bounds, a NaN bound opens that side completely
+    fn enclosing(lo: f64, hi: f64) -> Interval {
+        let lo = if lo.is_nan() { f64::NEG_INFINITY } else { lo };
+        let hi = if hi.is_nan() { f64::INFINITY } else { hi };
+        Interval::new(lo, hi)
+    }
+
+    /// Smallest interval containing every `(lo, hi)` pair, the whole real line
+    /// when any bound is NaN
+    fn hull_of(bounds: &[(f64, f64)]) -> Interval {
+        if bounds.iter().any(|b| b.0.is_nan() || b.1.is_nan()) {
+            return Interval::entire();
+        }
+        let lo = bounds.iter().map(|b| b.0).fold(f64::INFINITY, f64::min);
+        let hi = bounds.iter().map(|b| b.1).fold(f64::NEG_INFINITY, f64::max);
+        Interval::new(lo, hi)
+    }
+
+    /// Widens both bounds by `ulps` units in the last place
+    fn widen(lo: f64, hi: f64, ulps: u32) -> Interval {
+        Interval::new(down(lo, ulps), up(hi, ulps))
+    }
+
+    /// Applies a monotonically increasing library function to both bounds
+    fn increasing(&self, f: impl Fn(f64) -> f64) -> Interval {
+        Interval::widen(f(self.lo), f(self.hi), LIBM_ULPS)
+    }
+
+    /// Encloses a sine-like function whose maxima sit at `peak + 2k*pi`
+    /// and minima at `peak + (2k+1)*pi`
+    ///
+    /// The function is evaluated at the original bounds, so no rounding error
+    /// from shifting the argument enters the result.
+    fn periodic(&self, f: impl Fn(f64) -> f64, peak: f64) -> Interval {
+        use std::f64::consts::PI;
+        let span = self.hi - self.lo;
+        if span.is_nan() || span >= 2.0 * PI {
+            return Interval::new(-1.0, 1.0);
+        }
+        let (a, b) = (f(self.lo), f(self.hi));
+        let mut min = a.min(b);
+        let mut max = a.max(b);
+        // Extremum indices must fit in i64 with room for the margins below
+        let (q_lo, q_hi) = ((self.lo - peak) / PI, (self.hi - peak) / PI);
+        let limit = 2f64.powi(62);
+        if !(q_lo.abs() < limit && q_hi.abs() < limit) {
+            return Interval::new(-1.0, 1.0);
+        }
+        // The checks err towards inclusion, which only loosens the enclosure
+        let k_lo = q_lo.floor() as i64 - 1;
+        let k_hi = q_hi.ceil() as i64 + 1;
+        for k in k_lo..=k_hi {
+            let x = peak + k as f64 * PI;
+            let slack = 1e-12 * x.abs().max(1.0);
+            if x >= self.lo - slack && x <= self.hi + slack {
+                if k % 2 == 0 {
+                    max = 1.0;
+                } else {
+                    min = -1.0;
+                }
+            }
+        }
+        Interval::widen(min, max, LIBM_ULPS).clamp_unit()
+    }
+
+    fn clamp_unit(self) -> Interval {
+        Interval::new(self.lo.max(-1.0), self.hi.min(1.0))
+    }
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}, {}]", self.lo, self.hi)
+    }
+}
+
+impl From<f64> for Interval {
+    fn from(v: f64) -> Self {
+        Interval::point(v)
+    }
+}
+
+impl PartialOrd for Interval {
+    fn partial_cmp(&self, other: &Interval) -> Option<Ordering> {
+        if self == other {
+            Some(Ordering::Equal)
+        } else if self.hi < other.lo {
+            Some(Ordering::Less)
+        } else if self.lo > other.hi {
+            Some(Ordering::Greater)
+        } else {
+            None
+        }
+    }
+}
+
+impl Add for Interval {
+    type Output = Interval;
+
+    fn add(self, other: Interval) -> Interval {
+        let (lo, elo) = sum_with_error(self.lo, other.lo);
+        let (hi, ehi) = sum_with_error(self.hi, other.hi);
+        Interval::enclosing(lower(lo, elo), upper(hi, ehi))
+    }
+}
+
+impl Sub for Interval {
+    type Output = Interval;
+
+    fn sub(self, other: Interval) -> Interval {
+        self + -other
+    }
+}
+
+impl Mul for Interval {
+    type Output = Interval;
+
+    fn mul(self, other: Interval) -> Interval {
+        let bounds = [
+            product_bounds(self.lo, other.lo),
+            product_bounds(self.lo, other.hi),
+            product_bounds(self.hi, other.lo),
+            product_bounds(self.hi, other.hi),
+        ];
+        Interval::hull_of(&bounds)
+    }
+}
+
+impl Div for Interval {
+    type Output = Interval;
+
+    /// Division by an interval containing zero gives the whole real line
+    fn div(self, other: Interval) -> Interval {
+        if other.contains(0.0) {
+            return Interval::entire();
+        }
+        let bounds = [
+            quotient_bounds(self.lo, other.lo),
+            quotient_bounds(self.lo, other.hi),
+            quotient_bounds(self.hi, other.lo),
+            quotient_bounds(self.hi, other.hi),
+        ];
+        Interval::hull_of(&bounds)
+    }
+}
+
+impl Neg for Interval {
+    type Output = Interval;
+
+    fn neg(self) -> Interval {
+        Interval::new(-self.hi, -self.lo)
+    }
+}
+
+impl Scalar for Interval {
+    fn zero() -> Self {
+        Interval::point(0.0)
+    }
+
+    fn one() -> Self {
+        Interval::point(1.0)
+    }
+
+    fn from_i32(v: i32) -> Self {
+        Interval::point(f64::from(v))
+    }
+
+    /// Midpoint of the interval
+    fn to_f64(&self) -> f64 {
+        self.midpoint()
+    }
+
+    fn try_from_f64(v: f64) -> Option<Self> {
+        Some(Interval::point(v)).filter(|i| i.is_finite())
+    }
+
+    fn is_finite(&self) -> bool {
+        self.lo.is_finite() && self.hi.is_finite()
+    }
+
+    fn abs(&self) -> Self {
+        if self.lo >= 0.0 {
+            *self
+        } else if self.hi <= 0.0 {
+            -*self
+        } else {
+            Interval::new(0.0, self.hi.max(-self.lo))
+        }
+    }
+
+    fn min_of(self, other: Self) -> Self {
+        Interval::new(self.lo.min(other.lo), self.hi.min(other.hi))
+    }
+
+    fn max_of(self, other: Self) -> Self {
+        Interval::new(self.lo.max(other.lo), self.hi.max(other.hi))
+    }
+
+    /// Possibly collinear points count as collinear
+    fn collinear(a: &Point<Self>, b: &Point<Self>, c: &Point<Self>) -> bool {
+        let n = (*b - *a).cross(&(*c - *a));
+        n.to_array().iter().all(|v| v.contains(0.0))
+    }
+}
+
+impl Field for Interval {}
+
+impl Real for Interval {
+    fn from_f64(v: f64) -> Self {
+        Interval::point(v)
+    }
+
+    fn pi() -> Self {
+        let pi = std::f64::consts::PI;
+        Interval::new(pi, next_up(pi))
+    }
+
+    /// Negative parts of the interval are clamped to zero
+    fn sqrt(&self) -> Self {
+        let bound = |v: f64| {
+            let s = v.max(0.0).sqrt();
+            let error = (-s).mul_add(s, v.max(0.0));
+            (lower(s, error), upper(s, error))
+        };
+        Interval::new(bound(self.lo).0, bound(self.hi).1)
+    }
+
+    fn sin(&self) -> Self {
+        self.periodic(f64::sin, std::f64::consts::FRAC_PI_2)
+    }
+
+    fn cos(&self) -> Self {
+        self.periodic(f64::cos, 0.0)
+    }
+
+    /// The whole real line when the interval contains a pole
+    fn tan(&self) -> Self {
+        use std::f64::consts::{FRAC_PI_2, PI};
+        // Poles sit at pi/2 + k*pi, the margins err towards including one
+        let k_lo = ((self.lo - FRAC_PI_2) / PI - 1e-12).ceil();
+        let k_hi = ((self.hi - FRAC_PI_2) / PI + 1e-12).floor();
+        let span = self.hi - self.lo;
+        if k_lo <= k_hi || span.is_nan() || span >= PI {
+            return Interval::entire();
+        }
+        self.increasing(f64::tan)
+    }
+
+    fn asin(&self) -> Self {
+        let clamped = Interval::new(self.lo.max(-1.0), self.hi.min(1.0));
+        clamped.increasing(f64::asin)
+    }
+
+    fn acos(&self) -> Self {
+        let clamped = Interval::new(self.lo.max(-1.0), self.hi.min(1.0));
+        Interval::widen(clamped.hi.acos(), clamped.lo.acos(), LIBM_ULPS)
+    }
+
+    /// `[-pi, pi]` when the box touches the origin or crosses the negative x axis
+    fn atan2(&self, x: &Self) -> Self {
+        let pi = Interval::pi();
+        if (x.lo <= 0.0 && self.contains(0.0)) || !self.is_finite() || !x.is_finite() {
+            return Interval::new(-pi.hi, pi.hi);
+        }
+        let corners = [
+            self.lo.atan2(x.lo),
+            self.lo.atan2(x.hi),
+            self.hi.atan2(x.lo),
+            self.hi.atan2(x.hi),
+        ];
+        let lo = corners.iter().cloned().fold(f64::INFINITY, f64::min);
+        let hi = corners.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+        Interval::widen(lo, hi, LIBM_ULPS)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn outward_rounding_test() {
+        let tenth = Interval::point(0.1);
+        let sum = tenth + Interval::point(0.2);
+        assert!(sum.lo() < sum.hi());
+        assert!(sum.contains(0.30000000000000004));
+        assert_eq!(
+            Interval::point(0.5) + Interval::point(0.25),
+            Interval::point(0.75)
+        );
+        let third = Interval::one() / Interval::from_i32(3);
+        assert!(third.lo() < 1.0 / 3.0 || third.hi() > 1.0 / 3.0);
+        assert!((third * Interval::from_i32(3)).contains(1.0));
+        assert_eq!(Interval::point(4.0).sqrt(), Interval::point(2.0));
+        let root2 = Interval::from_i32(2).sqrt();
+        assert!(root2.lo() < root2.hi() && (root2 * root2).contains(2.0));
+    }
+
+    #[test]
+    fn next_float_test() {
+        assert_eq!(next_up(1.0), 1.0 + f64::EPSILON);
+        assert_eq!(next_down(1.0), 1.0 - f64::EPSILON / 2.0);
+        assert_eq!(next_up(-0.0), f64::from_bits(1));
+        assert_eq!(next_down(0.0), -f64::from_bits(1));
+        assert_eq!(next_up(f64::MAX), f64::INFINITY);
+        assert_eq!(next_up(f64::NEG_INFINITY), f64::MIN);
+        assert!(next_up(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn comparison_test() {
+        let a = Interval::new(0.0, 1.0);
+        let b = Interval::new(0.5, 2.0);
+        assert_eq!(a.partial_cmp(&b), None);
+        assert!(a < Interval::new(1.5, 2.0));
+        assert_eq!(a.min_of(b), Interval::new(0.0, 1.0));
+        assert_eq!(Interval::new(-3.0, 1.0).abs(), Interval::new(0.0, 3.0));
+        assert_eq!(a / Interval::new(-1.0, 1.0), Interval::entire());
+    }
+
+    #[test]
+    fn transcendental_test() {
+        let around_half_pi = Interval::new(1.5, 1.6);
+        let s = around_half_pi.sin();
+        assert_eq!(s.hi(), 1.0);
+        assert!(s.contains(1.5_f64.sin()) && s.contains(1.6_f64.sin()));
+        let c = Interval::new(3.0, 3.5).cos();
+        assert_eq!(c.lo(), -1.0);
+        assert!(Interval::pi().contains(std::f64::consts::PI));
+        assert!(Interval::new(1.0, 2.0)
+            .atan2(&Interval::new(1.0, 2.0))
+            .contains(std::f64::consts::FRAC_PI_4));
+        assert_eq!(Interval::new(1.5, 1.6).tan(), Interval::entire());
+        let t = Interval::new(0.1, 0.2).tan();
+        assert!(t.contains(0.1_f64.tan()) && t.contains(0.2_f64.tan()) && t.hi() < 1.0);
+    }
+
+    #[test]
+    fn sine_encloses_near_zeros_test() {
+        use std::f64::consts::PI;
+        assert!(Interval::point(0.0).sin().contains(0.0));
+        // sin(1e-10) is 1e-10 - 1.7e-31, between 1e-10 and the f64 just below it
+        let tiny = Interval::point(1e-10).sin();
+        assert!(tiny.contains(1e-10) && tiny.contains(next_down(1e-10)));
+        // The f64 nearest pi is below pi, so its sine is a tiny positive number
+        let near_pi = Interval::point(PI).sin();
+        assert!(near_pi.contains(1.2246467991473532e-16));
+        assert!(near_pi.lo() > 0.0);
+        let huge = Interval::point(1e300);
+        assert_eq!(huge.sin(), Interval::new(-1.0, 1.0));
+        assert_eq!((-huge).cos(), Interval::new(-1.0, 1.0));
+    }
+
+    #[test]
+    fn infinite_bounds_test() {
+        assert_eq!(Interval::entire() * Interval::point(0.0), Interval::zero());
+        let unbounded = Interval::one() / Interval::new(0.0, 1.0);
+        assert_eq!(unbounded * Interval::zero(), Interval::zero());
+        let sum = Interval::entire() + Interval::point(f64::INFINITY);
+        assert_eq!(sum.hi(), f64::INFINITY);
+        assert_eq!(Interval::entire() - Interval::entire(), Interval::entire());
+    }
+
+    #[test]
+    fn certified_distance_test() {
+        let a = Point::<Interval>::from_f64(&Point::new(0.1, 0.2, 0.3)).unwrap();
+        let b = Point::<Interval>::from_f64(&Point::new(1.1, -0.7, 2.3)).unwrap();
+        let d = a.distance(&b);
+        let exact = (1.0_f64 + 0.81 + 4.0).sqrt();
+        assert!(d.contains(exact));
+        assert!(d.width() < 1e-14);
+    }
+}
@@ -1,2 +1,51 @@
+pub mod angle;
+pub mod approx;
+pub mod bounds;
+pub mod coordinates;
+pub mod crs;
+pub mod dimension;
 pub mod dims;
-pub use dims::{Type,Dimensional};
+pub mod error;
+#[cfg(feature = "exact")]
+pub mod exact;
+pub mod finite;
+pub mod fixed;
+pub mod geodetic;
+pub mod interval;
+pub mod linear;
+pub mod matrix;
+pub mod parse;
+pub mod pointn;
+pub mod polyline;
+pub mod predicates;
+pub mod projection;
+pub mod quaternion;
+pub mod round;
+pub mod scalar;
+pub mod transform;
+pub mod vector;
+pub use angle::Angle;
+pub use approx::ApproxEq;
+pub use bounds::{Aabb,Aabb2,Aabb3,Obb};
+pub use coordinates::{Cylindrical,Polar,Spherical};
+pub use crs::{Crs,CrsDefinition,CrsRegistry,Reprojectable,Tagged};
+pub use dimension::{Dimension,SameDimension,D1,D2,D3};
+pub use dims::{Point,PointOf,Type,Dimensional};
+pub use error::GeometryError;
+#[cfg(feature = "exact")]
+pub use exact::Rational;
+pub use finite::FinitePoint;
+pub use fixed::Fixed;
+pub use geodetic::{Ellipsoid,EnuFrame,Geodetic};
+pub use interval::Interval;
+pub use linear::{Intersect,Intersection,Line,Overlap,Plane,Ray,Segment};
+pub use matrix::{Matrix3,Matrix4};
+pub use parse::read_points;
+pub use pointn::{PointN,Point1,Point2,Point3};
+pub use polyline::Polyline;
+pub use projection::{Equirectangular,Projection,Utm,WebMercator};
+pub use quaternion::{EulerOrder,Quaternion};
+pub use round::{Capsule,Circle,Cylinder,Ellipse,Sphere};
+pub use scalar::{Field,Real,Scalar};
+pub use transform::{Affine3,Transformable,TryTransformable};
+pub use vector::Vector3;
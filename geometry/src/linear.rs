@@ -0,0 +1,844 @@
+//! Linear primitives: lines, rays, segments and planes in three dimensions
+//!
+//! Queries treat coordinates within a small relative tolerance of each other
+//! as equal, so nearly parallel lines that overlap in floating point are
+//! reported as overlapping rather than crossing far away.
+
+use crate::dimension::{SameDimension, D3};
+use crate::dims::{Dimensional, Point, Type};
+use crate::error::GeometryError;
+use crate::transform::{Affine3, Transformable, TryTransformable};
+use crate::vector::Vector3;
+
+/// Relative tolerance for intersection and parallelism tests
+const TOLERANCE: f64 = 1e-9;
+
+/// Absolute distance tolerance scaled to the magnitude of the given points
+fn tolerance(points: &[&Point]) -> f64 {
+    let scale = points
+        .iter()
+        .flat_map(|p| p.to_array())
+        .fold(1.0, |m: f64, v| m.max(v.abs()));
+    TOLERANCE * scale
+}
+
+/// Unit direction, failing for zero and non-finite vectors
+fn unit(direction: Vector3) -> Result<Vector3, GeometryError> {
+    if !direction.to_array().iter().all(|v| v.is_finite()) {
+        return Err(GeometryError::NonFinite);
+    }
+    direction.normalize().ok_or(GeometryError::Degenerate)
+}
+
+fn check_finite(p: &Point) -> Result<(), GeometryError> {
+    if p.to_array().iter().all(|v| v.is_finite()) {
+        Ok(())
+    } else {
+        Err(GeometryError::NonFinite)
+    }
+}
+
+/// Line struct represents an infinite straight line through a point
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Line {
+    origin: Point,
+    direction: Vector3,
+}
+
+/// Ray struct represents a half line starting at an origin
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ray {
+    origin: Point,
+    direction: Vector3,
+}
+
+/// Segment struct represents the straight path between two end points
+///
+/// Both ends may coincide, such a segment behaves like a single point.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Segment {
+    start: Point,
+    end: Point,
+}
+
+/// Plane struct represents the points `p` with `normal · p = offset`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Plane {
+    normal: Vector3,
+    offset: f64,
+}
+
+/// Overlap enum list of shapes two primitives can share beyond a single point
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Overlap {
+    Segment(Segment),
+    Ray(Ray),
+    Line(Line),
+    Plane(Plane),
+}
+
+/// Intersection enum list of outcomes of an intersection query
+///
+/// Two crossing planes share a line, which is reported as an [`Overlap`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Intersection {
+    None,
+    Point(Point),
+    Overlap(Overlap),
+}
+
+/// Intersect trait supplies intersection queries between shapes of the same space
+pub trait Intersect<Rhs = Self>: SameDimension<Rhs> {
+    /// Points shared by both shapes
+    fn intersect(&self, other: &Rhs) -> Intersection;
+}
+
+/// The points `origin + direction * t` with `t` in `[lo, hi]`, bounds may be infinite
+#[derive(Debug, Clone, Copy)]
+struct Span {
+    origin: Point,
+    direction: Vector3,
+    lo: f64,
+    hi: f64,
+}
+
+impl Span {
+    fn at(&self, t: f64) -> Point {
+        self.origin + self.direction * t
+    }
+
+    fn is_point(&self) -> bool {
+        self.direction.length_squared() == 0.0
+    }
+
+    /// Parameter of the orthogonal projection of `p` on the carrier line
+    fn parameter_of(&self, p: &Point) -> f64 {
+        if self.is_point() {
+            return 0.0;
+        }
+        (*p - self.origin).dot(&self.direction) / self.direction.length_squared()
+    }
+
+    fn closest_point(&self, p: &Point) -> Point {
+        self.at(self.parameter_of(p).clamp(self.lo, self.hi))
+    }
+
+    /// True when `t` lies in the parameter range, allowing `tol` in distance
+    fn contains_parameter(&self, t: f64, tol: f64) -> bool {
+        let slack = tol / self.direction.length();
+        t >= self.lo - slack && t <= self.hi + slack
+    }
+
+    fn bounding_box(&self) -> (Point, Point) {
+        let o = self.origin.to_array();
+        let d = self.direction.to_array();
+        let (mut lo, mut hi) = (o, o);
+        for i in 0..3 {
+            if d[i] != 0.0 {
+                let (a, b) = (o[i] + d[i] * self.lo, o[i] + d[i] * self.hi);
+                lo[i] = a.min(b);
+                hi[i] = a.max(b);
+            }
+        }
+        (Point::from(lo), Point::from(hi))
+    }
+
+    /// Shape covering the span, a point when the range is empty
+    fn to_intersection(self) -> Intersection {
+        match (self.lo.is_finite(), self.hi.is_finite()) {
+            (true, true) if self.lo == self.hi || self.is_point() => {
+                Intersection::Point(self.at(self.lo))
+            }
+            (true, true) => Intersection::Overlap(Overlap::Segment(Segment {
+                start: self.at(self.lo),
+                end: self.at(self.hi),
+            })),
+            (true, false) => Intersection::Overlap(Overlap::Ray(Ray {
+                origin: self.at(self.lo),
+                direction: self.direction.normalize().unwrap_or(self.direction),
+            })),
+            (false, true) => Intersection::Overlap(Overlap::Ray(Ray {
+                origin: self.at(self.hi),
+                direction: -self.direction.normalize().unwrap_or(self.direction),
+            })),
+            (false, false) => Intersection::Overlap(Overlap::Line(Line {
+                origin: self.origin,
+                direction: self.direction.normalize().unwrap_or(self.direction),
+            })),
+        }
+    }
+}
+
+/// Intersection of a point with a span
+fn point_span(p: &Point, s: &Span) -> Intersection {
+    let tol = tolerance(&[p, &s.origin]);
+    if s.closest_point(p).distance(p) <= tol {
+        Intersection::Point(*p)
+    } else {
+        Intersection::None
+    }
+}
+
+fn span_span(a: &Span, b: &Span) -> Intersection {
+    if a.is_point() {
+        return point_span(&a.origin, b);
+    }
+    if b.is_point() {
+        return point_span(&b.origin, a);
+    }
+    let tol = tolerance(&[&a.origin, &b.origin]);
+    let (da, db) = (a.direction, b.direction);
+    let w = b.origin - a.origin;
+    let c = da.cross(&db);
+    let cc = c.length_squared();
+    if cc <= TOLERANCE * TOLERANCE * da.length_squared() * db.length_squared() {
+        // Parallel carriers overlap only when b's origin lies on a's line
+        let t0 = w.dot(&da) / da.length_squared();
+        if a.at(t0).distance(&b.origin) > tol {
+            return Intersection::None;
+        }
+        let k = db.dot(&da) / da.length_squared();
+        let (mut lo, mut hi) = (t0 + k * b.lo, t0 + k * b.hi);
+        if k < 0.0 {
+            std::mem::swap(&mut lo, &mut hi);
+        }
+        let (lo, hi) = (lo.max(a.lo), hi.min(a.hi));
+        let slack = tol / da.length();
+        if lo > hi + slack {
+            return Intersection::None;
+        }
+        if hi - lo <= slack {
+            return Intersection::Point(a.at((lo + hi) / 2.0));
+        }
+        return Span { lo, hi, ..*a }.to_intersection();
+    }
+    let t = w.cross(&db).dot(&c) / cc;
+    let s = w.cross(&da).dot(&c) / cc;
+    let p = a.at(t);
+    if p.distance(&b.at(s)) <= tol && a.contains_parameter(t, tol) && b.contains_parameter(s, tol) {
+        Intersection::Point(p)
+    } else {
+        Intersection::None
+    }
+}
+
+fn span_plane(a: &Span, plane: &Plane) -> Intersection {
+    let tol = tolerance(&[&a.origin, &plane.anchor()]);
+    let height = plane.signed_distance(&a.origin);
+    if a.is_point() {
+        return if height.abs() <= tol {
+            Intersection::Point(a.origin)
+        } else {
+            Intersection::None
+        };
+    }
+    let rate = plane.normal.dot(&a.direction);
+    if rate.abs() <= TOLERANCE * a.direction.length() {
+        return if height.abs() <= tol {
+            a.to_intersection()
+        } else {
+            Intersection::None
+        };
+    }
+    let t = -height / rate;
+    if a.contains_parameter(t, tol) {
+        Intersection::Point(a.at(t.clamp(a.lo, a.hi)))
+    } else {
+        Intersection::None
+    }
+}
+
+impl Line {
+    /// Line through `origin` along `direction`, failing for a zero or non-finite direction
+    pub fn new(origin: Point, direction: Vector3) -> Result<Self, GeometryError> {
+        check_finite(&origin)?;
+        Ok(Line {
+            origin,
+            direction: unit(direction)?,
+        })
+    }
+
+    /// Line through two distinct points, directed from `a` to `b`
+    pub fn through(a: Point, b: Point) -> Result<Self, GeometryError> {
+        Line::new(a, b - a)
+    }
+
+    /// Point the line was constructed through
+    pub fn origin(&self) -> Point {
+        self.origin
+    }
+
+    /// Unit direction
+    pub fn direction(&self) -> Vector3 {
+        self.direction
+    }
+
+    /// Point at signed distance `t` from the origin along the direction
+    pub fn point_at(&self, t: f64) -> Point {
+        self.span().at(t)
+    }
+
+    /// Signed distance from the origin to the projection of `p`
+    pub fn parameter_of(&self, p: &Point) -> f64 {
+        self.span().parameter_of(p)
+    }
+
+    /// Orthogonal projection of `p` onto the line
+    pub fn project(&self, p: &Point) -> Point {
+        self.span().closest_point(p)
+    }
+
+    /// Point on the line closest to `p`, the same as [`Line::project`]
+    pub fn closest_point(&self, p: &Point) -> Point {
+        self.project(p)
+    }
+
+    /// Distance from `p` to the line
+    pub fn distance(&self, p: &Point) -> f64 {
+        self.closest_point(p).distance(p)
+    }
+
+    fn span(&self) -> Span {
+        Span {
+            origin: self.origin,
+            direction: self.direction,
+            lo: f64::NEG_INFINITY,
+            hi: f64::INFINITY,
+        }
+    }
+}
+
+impl Ray {
+    /// Ray from `origin` along `direction`, failing for a zero or non-finite direction
+    pub fn new(origin: Point, direction: Vector3) -> Result<Self, GeometryError> {
+        check_finite(&origin)?;
+        Ok(Ray {
+            origin,
+            direction: unit(direction)?,
+        })
+    }
+
+    /// Start of the ray
+    pub fn origin(&self) -> Point {
+        self.origin
+    }
+
+    /// Unit direction
+    pub fn direction(&self) -> Vector3 {
+        self.direction
+    }
+
+    /// Point at distance `t` from the origin, `t` should not be negative
+    pub fn point_at(&self, t: f64) -> Point {
+        self.span().at(t)
+    }
+
+    /// Signed distance along the supporting line to the projection of `p`
+    pub fn parameter_of(&self, p: &Point) -> f64 {
+        self.span().parameter_of(p)
+    }
+
+    /// Orthogonal projection of `p` onto the supporting line
+    pub fn project(&self, p: &Point) -> Point {
+        self.point_at(self.parameter_of(p))
+    }
+
+    /// Point on the ray closest to `p`
+    pub fn closest_point(&self, p: &Point) -> Point {
+        self.span().closest_point(p)
+    }
+
+    /// Distance from `p` to the ray
+    pub fn distance(&self, p: &Point) -> f64 {
+        self.closest_point(p).distance(p)
+    }
+
+    /// Line containing the ray
+    pub fn to_line(&self) -> Line {
+        Line {
+            origin: self.origin,
+            direction: self.direction,
+        }
+    }
+
+    fn span(&self) -> Span {
+        Span {
+            origin: self.origin,
+            direction: self.direction,
+            lo: 0.0,
+            hi: f64::INFINITY,
+        }
+    }
+}
+
+impl Segment {
+    /// Segment from `start` to `end`, failing for non-finite end points
+    pub fn new(start: Point, end: Point) -> Result<Self, GeometryError> {
+        check_finite(&start)?;
+        check_finite(&end)?;
+        Ok(Segment { start, end })
+    }
+
+    /// Segment between end points already known to be finite
+    pub(crate) fn new_unchecked(start: Point, end: Point) -> Self {
+        Segment { start, end }
+    }
+
+    /// First end point
+    pub fn start(&self) -> Point {
+        self.start
+    }
+
+    /// Second end point
+    pub fn end(&self) -> Point {
+        self.end
+    }
+
+    /// Vector from start to end
+    pub fn direction(&self) -> Vector3 {
+        self.end - self.start
+    }
+
+    /// Distance between the end points
+    pub fn length(&self) -> f64 {
+        self.start.distance(&self.end)
+    }
+
+    /// Point halfway between the end points
+    pub fn midpoint(&self) -> Point {
+        self.start.lerp(&self.end, 0.5)
+    }
+
+    /// Interpolated point, `t = 0` gives the start and `t = 1` the end
+    pub fn point_at(&self, t: f64) -> Point {
+        self.span().at(t)
+    }
+
+    /// Interpolation parameter of the projection of `p` on the supporting line
+    pub fn parameter_of(&self, p: &Point) -> f64 {
+        self.span().parameter_of(p)
+    }
+
+    /// Orthogonal projection of `p` onto the supporting line
+    pub fn project(&self, p: &Point) -> Point {
+        self.point_at(self.parameter_of(p))
+    }
+
+    /// Point on the segment closest to `p`
+    pub fn closest_point(&self, p: &Point) -> Point {
+        self.span().closest_point(p)
+    }
+
+    /// Distance from `p` to the segment
+    pub fn distance(&self, p: &Point) -> f64 {
+        self.closest_point(p).distance(p)
+    }
+
+    /// Line containing the segment, failing when the end points coincide
+    pub fn to_line(&self) -> Result<Line, GeometryError> {
+        Line::through(self.start, self.end)
+    }
+
+    fn span(&self) -> Span {
+        Span {
+            origin: self.start,
+            direction: self.direction(),
+            lo: 0.0,
+            hi: 1.0,
+        }
+    }
+}
+
+impl Plane {
+    /// Plane through `point` perpendicular to `normal`, failing for a zero or non-finite normal
+    pub fn new(normal: Vector3, point: Point) -> Result<Self, GeometryError> {
+        check_finite(&point)?;
+        let normal = unit(normal)?;
+        Ok(Plane {
+            normal,
+            offset: normal.dot(&point.to_vector()),
+        })
+    }
+
+    /// Plane through three points, its normal follows the right hand rule from `a` to `b` to `c`
+    pub fn from_points(a: Point, b: Point, c: Point) -> Result<Self, GeometryError> {
+        Plane::new((b - a).cross(&(c - a)), a)
+    }
+
+    /// Unit normal
+    pub fn normal(&self) -> Vector3 {
+        self.normal
+    }
+
+    /// Signed distance from the origin to the plane along the normal
+    pub fn offset(&self) -> f64 {
+        self.offset
+    }
+
+    /// Point of the plane closest to the coordinate origin
+    pub fn anchor(&self) -> Point {
+        Point::from(self.normal * self.offset)
+    }
+
+    /// Distance from the plane, positive on the side the normal points to
+    pub fn signed_distance(&self, p: &Point) -> f64 {
+        self.normal.dot(&p.to_vector()) - self.offset
+    }
+
+    /// Distance from the plane
+    pub fn distance(&self, p: &Point) -> f64 {
+        self.signed_distance(p).abs()
+    }
+
+    /// Orthogonal projection of `p` onto the plane
+    pub fn project(&self, p: &Point) -> Point {
+        *p - self.normal * self.signed_distance(p)
+    }
+
+    /// Point of the plane closest to `p`, the same as [`Plane::project`]
+    pub fn closest_point(&self, p: &Point) -> Point {
+        self.project(p)
+    }
+
+    /// Same plane with the normal reversed
+    pub fn flipped(&self) -> Plane {
+        Plane {
+            normal: -self.normal,
+            offset: -self.offset,
+        }
+    }
+}
+
+/// Implements [`Intersect`] between two span based primitives
+macro_rules! impl_span_intersect {
+    ($($a:ty => $($b:ty),+;)+) => {
+        $($(
+            impl Intersect<$b> for $a {
+                fn intersect(&self, other: &$b) -> Intersection {
+                    span_span(&self.span(), &other.span())
+                }
+            }
+        )+)+
+    };
+}
+
+impl_span_intersect! {
+    Line => Line, Ray, Segment;
+    Ray => Line, Ray, Segment;
+    Segment => Line, Ray, Segment;
+}
+
+/// Implements [`Intersect`] between a span based primitive and a plane, both ways round
+macro_rules! impl_plane_intersect {
+    ($($a:ty),+) => {
+        $(
+            impl Intersect<Plane> for $a {
+                fn intersect(&self, other: &Plane) -> Intersection {
+                    span_plane(&self.span(), other)
+                }
+            }
+
+            impl Intersect<$a> for Plane {
+                fn intersect(&self, other: &$a) -> Intersection {
+                    span_plane(&other.span(), self)
+                }
+            }
+        )+
+    };
+}
+
+impl_plane_intersect!(Line, Ray, Segment);
+
+impl Intersect for Plane {
+    fn intersect(&self, other: &Plane) -> Intersection {
+        let direction = self.normal.cross(&other.normal);
+        let uu = direction.length_squared();
+        if uu <= TOLERANCE * TOLERANCE {
+            let anchor = self.anchor();
+            return if other.distance(&anchor) <= tolerance(&[&anchor, &other.anchor()]) {
+                Intersection::Overlap(Overlap::Plane(*self))
+            } else {
+                Intersection::None
+            };
+        }
+        let origin = (other.normal.cross(&direction) * self.offset
+            + direction.cross(&self.normal) * other.offset)
+            / uu;
+        Intersection::Overlap(Overlap::Line(Line {
+            origin: Point::from(origin),
+            direction: direction / uu.sqrt(),
+        }))
+    }
+}
+
+impl TryTransformable for Line {
+    /// Maps the origin and direction, failing if `t` collapses the direction
+    fn try_transform(&self, t: &Affine3) -> Result<Self, GeometryError> {
+        Line::new(t.apply_point(&self.origin), t.apply_vector(&self.direction))
+    }
+}
+
+impl TryTransformable for Ray {
+    /// Maps the origin and direction, failing if `t` collapses the direction
+    fn try_transform(&self, t: &Affine3) -> Result<Self, GeometryError> {
+        Ray::new(t.apply_point(&self.origin), t.apply_vector(&self.direction))
+    }
+}
+
+impl Transformable for Segment {
+    fn transform(&self, t: &Affine3) -> Self {
+        Segment {
+            start: t.apply_point(&self.start),
+            end: t.apply_point(&self.end),
+        }
+    }
+}
+
+impl TryTransformable for Plane {
+    /// Maps the normal by the inverse transpose, failing for a singular `t`
+    fn try_transform(&self, t: &Affine3) -> Result<Self, GeometryError> {
+        let inverse = t.linear().inverse().ok_or(GeometryError::Degenerate)?;
+        let normal = inverse.transpose() * self.normal;
+        Plane::new(normal, t.apply_point(&self.anchor()))
+    }
+}
+
+impl Dimensional for Line {
+    type Dim = D3;
+
+    fn dimensions(&self) -> Type {
+        Type::D1
+    }
+
+    fn measure(&self) -> f64 {
+        f64::INFINITY
+    }
+
+    /// Infinite along every axis the line is not perpendicular to
+    fn bounding_box(&self) -> (Point, Point) {
+        self.span().bounding_box()
+    }
+
+    /// The line has no center of mass, the construction point is returned instead
+    fn centroid(&self) -> Point {
+        self.origin
+    }
+}
+
+impl Dimensional for Ray {
+    type Dim = D3;
+
+    fn dimensions(&self) -> Type {
+        Type::D1
+    }
+
+    fn measure(&self) -> f64 {
+        f64::INFINITY
+    }
+
+    fn bounding_box(&self) -> (Point, Point) {
+        self.span().bounding_box()
+    }
+
+    /// The ray has no center of mass, its origin is returned instead
+    fn centroid(&self) -> Point {
+        self.origin
+    }
+}
+
+impl Dimensional for Segment {
+    type Dim = D3;
+
+    fn dimensions(&self) -> Type {
+        Type::D1
+    }
+
+    fn measure(&self) -> f64 {
+        self.length()
+    }
+
+    fn bounding_box(&self) -> (Point, Point) {
+        (self.start.min(&self.end), self.start.max(&self.end))
+    }
+
+    fn centroid(&self) -> Point {
+        self.midpoint()
+    }
+}
+
+impl Dimensional for Plane {
+    type Dim = D3;
+
+    fn dimensions(&self) -> Type {
+        Type::D2
+    }
+
+    fn measure(&self) -> f64 {
+        f64::INFINITY
+    }
+
+    /// Finite only along an axis the plane is perpendicular to
+    fn bounding_box(&self) -> (Point, Point) {
+        let n = self.normal.to_array();
+        let a = self.anchor().to_array();
+        let axis = (0..3).find(|&i| (0..3).all(|j| j == i || n[j] == 0.0));
+        let lo = std::array::from_fn(|i| {
+            if Some(i) == axis {
+                a[i]
+            } else {
+                f64::NEG_INFINITY
+            }
+        });
+        let hi = std::array::from_fn(|i| if Some(i) == axis { a[i] } else { f64::INFINITY });
+        (Point::from(lo), Point::from(hi))
+    }
+
+    /// The plane has no center of mass, its point closest to the origin is returned instead
+    fn centroid(&self) -> Point {
+        self.anchor()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::approx::ApproxEq;
+
+    fn p(x: f64, y: f64, z: f64) -> Point {
+        Point::new(x, y, z)
+    }
+
+    #[test]
+    fn queries_test() {
+        let line = Line::through(p(0.0, 0.0, 0.0), p(2.0, 0.0, 0.0)).unwrap();
+        assert_eq!(line.project(&p(5.0, 3.0, 1.0)), p(5.0, 0.0, 0.0));
+        assert_eq!(line.distance(&p(5.0, 3.0, 4.0)), 5.0);
+        let ray = Ray::new(p(1.0, 0.0, 0.0), Vector3::unit_x()).unwrap();
+        assert_eq!(ray.closest_point(&p(-3.0, 1.0, 0.0)), p(1.0, 0.0, 0.0));
+        let seg = Segment::new(p(0.0, 0.0, 0.0), p(0.0, 4.0, 0.0)).unwrap();
+        assert_eq!(seg.closest_point(&p(1.0, 9.0, 0.0)), p(0.0, 4.0, 0.0));
+        assert_eq!(seg.parameter_of(&p(1.0, 1.0, 0.0)), 0.25);
+        let plane =
+            Plane::from_points(p(0.0, 0.0, 1.0), p(1.0, 0.0, 1.0), p(0.0, 1.0, 1.0)).unwrap();
+        assert_eq!(plane.signed_distance(&p(3.0, 3.0, -1.0)), -2.0);
+        assert_eq!(plane.project(&p(3.0, 3.0, -1.0)), p(3.0, 3.0, 1.0));
+        assert_eq!(
+            Line::new(p(0.0, 0.0, 0.0), Vector3::zero()),
+            Err(GeometryError::Degenerate)
+        );
+        assert_eq!(
+            Plane::from_points(p(0.0, 0.0, 0.0), p(1.0, 1.0, 1.0), p(2.0, 2.0, 2.0)),
+            Err(GeometryError::Degenerate)
+        );
+        assert_eq!(
+            Segment::new(p(f64::NAN, 0.0, 0.0), Point::origin()),
+            Err(GeometryError::NonFinite)
+        );
+    }
+
+    #[test]
+    fn linear_intersection_test() {
+        let a = Segment::new(p(0.0, 0.0, 0.0), p(2.0, 2.0, 0.0)).unwrap();
+        let b = Segment::new(p(0.0, 2.0, 0.0), p(2.0, 0.0, 0.0)).unwrap();
+        assert_eq!(a.intersect(&b), Intersection::Point(p(1.0, 1.0, 0.0)));
+        let skew = Line::new(p(0.0, 0.0, 1.0), Vector3::unit_y()).unwrap();
+        assert_eq!(a.intersect(&skew), Intersection::None);
+        let c = Segment::new(p(1.0, 1.0, 0.0), p(3.0, 3.0, 0.0)).unwrap();
+        assert_eq!(
+            a.intersect(&c),
+            Intersection::Overlap(Overlap::Segment(
+                Segment::new(p(1.0, 1.0, 0.0), p(2.0, 2.0, 0.0)).unwrap()
+            ))
+        );
+        let touching = Segment::new(p(2.0, 2.0, 0.0), p(5.0, 5.0, 0.0)).unwrap();
+        assert_eq!(
+            a.intersect(&touching),
+            Intersection::Point(p(2.0, 2.0, 0.0))
+        );
+        let ray = Ray::new(p(1.0, 0.0, 0.0), Vector3::unit_x()).unwrap();
+        let back = Ray::new(p(3.0, 0.0, 0.0), -Vector3::unit_x()).unwrap();
+        match ray.intersect(&back) {
+            Intersection::Overlap(Overlap::Segment(s)) => {
+                assert_eq!((s.start(), s.end()), (p(1.0, 0.0, 0.0), p(3.0, 0.0, 0.0)))
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+        let line = ray.to_line();
+        assert_eq!(
+            line.intersect(&ray),
+            Intersection::Overlap(Overlap::Ray(ray))
+        );
+    }
+
+    #[test]
+    fn plane_intersection_test() {
+        let floor = Plane::new(Vector3::unit_z(), Point::origin()).unwrap();
+        let seg = Segment::new(p(1.0, 1.0, -1.0), p(1.0, 1.0, 3.0)).unwrap();
+        assert_eq!(seg.intersect(&floor), Intersection::Point(p(1.0, 1.0, 0.0)));
+        let above = Ray::new(p(0.0, 0.0, 1.0), Vector3::unit_z()).unwrap();
+        assert_eq!(floor.intersect(&above), Intersection::None);
+        let lying = Line::new(Point::origin(), Vector3::unit_x()).unwrap();
+        assert_eq!(
+            floor.intersect(&lying),
+            Intersection::Overlap(Overlap::Line(lying))
+        );
+        let wall = Plane::new(Vector3::new(1.0, 0.0, 0.0), p(2.0, 0.0, 0.0)).unwrap();
+        match floor.intersect(&wall) {
+            Intersection::Overlap(Overlap::Line(l)) => {
+                assert!(l.distance(&p(2.0, 5.0, 0.0)) < 1e-12);
+                assert!(l.direction().cross(&Vector3::unit_y()).length() < 1e-12);
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+        assert_eq!(
+            floor.intersect(&floor.flipped()),
+            Intersection::Overlap(Overlap::Plane(floor))
+        );
+    }
+
+    #[test]
+    fn transform_test() {
+        let shear = Affine3::new(
+            crate::matrix::Matrix3::new([[1.0, 0.0, 1.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]),
+            Vector3::new(0.0, 0.0, 1.0),
+        );
+        let tilted = Plane::from_points(p(1.0, 0.0, 0.0), p(0.0, 1.0, 0.0), p(0.0, 0.0, 1.0))
+            .unwrap()
+            .try_transform(&shear)
+            .unwrap();
+        for q in [p(1.0, 0.0, 0.0), p(0.0, 1.0, 0.0), p(0.0, 0.0, 1.0)] {
+            assert!(tilted.distance(&shear.apply_point(&q)) < 1e-12);
+        }
+        let flatten = Affine3::scaling(1.0, 1.0, 0.0);
+        let floor = Plane::new(Vector3::unit_z(), Point::origin()).unwrap();
+        assert_eq!(
+            floor.try_transform(&flatten),
+            Err(GeometryError::Degenerate)
+        );
+        let up = Line::new(Point::origin(), Vector3::unit_z()).unwrap();
+        assert_eq!(up.try_transform(&flatten), Err(GeometryError::Degenerate));
+        let ray = Ray::new(p(1.0, 0.0, 0.0), Vector3::unit_x()).unwrap();
+        let moved = ray.try_scaled(2.0).unwrap();
+        assert_eq!(
+            (moved.origin(), moved.direction()),
+            (p(2.0, 0.0, 0.0), Vector3::unit_x())
+        );
+        let seg = Segment::new(p(0.0, 0.0, 1.0), p(0.0, 0.0, 3.0)).unwrap();
+        assert_eq!(seg.transform(&flatten).length(), 0.0);
+    }
+
+    #[test]
+    fn dimensional_test() {
+        let seg = Segment::new(p(0.0, 3.0, 0.0), p(4.0, 0.0, 0.0)).unwrap();
+        assert_eq!((seg.dimensions(), seg.measure()), (Type::D1, 5.0));
+        assert!(seg.centroid().abs_diff_eq(&p(2.0, 1.5, 0.0), 0.0));
+        let ray = Ray::new(p(1.0, 2.0, 3.0), Vector3::unit_x()).unwrap();
+        assert_eq!(
+            ray.bounding_box(),
+            (p(1.0, 2.0, 3.0), p(f64::INFINITY, 2.0, 3.0))
+        );
+        let floor = Plane::new(Vector3::unit_z(), p(0.0, 0.0, 2.0)).unwrap();
+        assert_eq!(floor.dimensions(), Type::D2);
+        assert_eq!(floor.bounding_box().0.z(), 2.0);
+    }
+}
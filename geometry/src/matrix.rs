@@ -0,0 +1,246 @@
+use std::ops::Mul;
+
+use crate::dims::Point;
+use crate::vector::Vector3;
+
+/// Matrix3 struct represents a row-major 3x3 matrix acting on column vectors
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Matrix3 {
+    rows: [[f64; 3]; 3],
+}
+
+/// Matrix4 struct represents a row-major 4x4 matrix acting on homogeneous column vectors
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Matrix4 {
+    rows: [[f64; 4]; 4],
+}
+
+impl Matrix3 {
+    /// Creates a matrix from its rows
+    pub fn new(rows: [[f64; 3]; 3]) -> Self {
+        Matrix3 { rows }
+    }
+
+    /// Creates a matrix whose columns are the given vectors
+    pub fn from_columns(x: Vector3, y: Vector3, z: Vector3) -> Self {
+        Matrix3::new([x.to_array(), y.to_array(), z.to_array()]).transpose()
+    }
+
+    /// The identity matrix
+    pub fn identity() -> Self {
+        Matrix3::diagonal(1.0, 1.0, 1.0)
+    }
+
+    /// Matrix with the given diagonal and zeros elsewhere
+    pub fn diagonal(x: f64, y: f64, z: f64) -> Self {
+        Matrix3::new([[x, 0.0, 0.0], [0.0, y, 0.0], [0.0, 0.0, z]])
+    }
+
+    /// Rows of the matrix
+    pub fn rows(&self) -> &[[f64; 3]; 3] {
+        &self.rows
+    }
+
+    /// Element at `row`, `col`
+    pub fn get(&self, row: usize, col: usize) -> f64 {
+        self.rows[row][col]
+    }
+
+    /// Column `col` as a vector
+    pub fn column(&self, col: usize) -> Vector3 {
+        Vector3::new(self.rows[0][col], self.rows[1][col], self.rows[2][col])
+    }
+
+    /// Transposed matrix
+    pub fn transpose(&self) -> Self {
+        Matrix3::new(std::array::from_fn(|r| {
+            std::array::from_fn(|c| self.rows[c][r])
+        }))
+    }
+
+    /// Determinant
+    pub fn determinant(&self) -> f64 {
+        self.column(0).dot(&self.column(1).cross(&self.column(2)))
+    }
+
+    /// Inverse matrix, `None` when singular
+    pub fn inverse(&self) -> Option<Self> {
+        let det = self.determinant();
+        if det == 0.0 || !det.is_finite() {
+            return None;
+        }
+        let [c0, c1, c2] = [self.column(0), self.column(1), self.column(2)];
+        let rows = [c1.cross(&c2), c2.cross(&c0), c0.cross(&c1)];
+        Some(Matrix3::new(rows.map(|r| (r / det).to_array())))
+    }
+}
+
+impl Matrix4 {
+    /// Creates a matrix from its rows
+    pub fn new(rows: [[f64; 4]; 4]) -> Self {
+        Matrix4 { rows }
+    }
+
+    /// The identity matrix
+    pub fn identity() -> Self {
+        Matrix4::new(std::array::from_fn(|r| {
+            std::array::from_fn(|c| if r == c { 1.0 } else { 0.0 })
+        }))
+    }
+
+    /// Rows of the matrix
+    pub fn rows(&self) -> &[[f64; 4]; 4] {
+        &self.rows
+    }
+
+    /// Element at `row`, `col`
+    pub fn get(&self, row: usize, col: usize) -> f64 {
+        self.rows[row][col]
+    }
+
+    /// Transposed matrix
+    pub fn transpose(&self) -> Self {
+        Matrix4::new(std::array::from_fn(|r| {
+            std::array::from_fn(|c| self.rows[c][r])
+        }))
+    }
+
+    /// Determinant by cofactor expansion along the first row
+    pub fn determinant(&self) -> f64 {
+        (0..4)
+            .map(|c| {
+                let minor = self.minor(0, c);
+                let sign = if c % 2 == 0 { 1.0 } else { -1.0 };
+                sign * self.rows[0][c] * minor.determinant()
+            })
+            .sum()
+    }
+
+    /// The 3x3 matrix left after removing `row` and `col`
+    fn minor(&self, row: usize, col: usize) -> Matrix3 {
+        let skip = |i: usize, s: usize| if i < s { i } else { i + 1 };
+        Matrix3::new(std::array::from_fn(|r| {
+            std::array::from_fn(|c| self.rows[skip(r, row)][skip(c, col)])
+        }))
+    }
+
+    /// Inverse matrix by Gauss-Jordan elimination with partial pivoting, `None` when singular
+    pub fn inverse(&self) -> Option<Self> {
+        let mut a = self.rows;
+        let mut inv = Matrix4::identity().rows;
+        for col in 0..4 {
+            let pivot = (col..4).max_by(|&i, &j| a[i][col].abs().total_cmp(&a[j][col].abs()))?;
+            if a[pivot][col] == 0.0 || !a[pivot][col].is_finite() {
+                return None;
+            }
+            a.swap(col, pivot);
+            inv.swap(col, pivot);
+            let p = a[col][col];
+            for k in 0..4 {
+                a[col][k] /= p;
+                inv[col][k] /= p;
+            }
+            for row in 0..4 {
+                if row != col {
+                    let f = a[row][col];
+                    for k in 0..4 {
+                        a[row][k] -= f * a[col][k];
+                        inv[row][k] -= f * inv[col][k];
+                    }
+                }
+            }
+        }
+        Some(Matrix4::new(inv))
+    }
+
+    /// Applies the matrix to a point, dividing by the homogeneous coordinate
+    pub fn transform_point(&self, p: &Point) -> Point {
+        let [x, y, z, w] = self.apply([p.x(), p.y(), p.z(), 1.0]);
+        Point::new(x / w, y / w, z / w)
+    }
+
+    /// Applies the matrix to a direction, ignoring the translation column
+    pub fn transform_vector(&self, v: &Vector3) -> Vector3 {
+        let [x, y, z, _] = self.apply([v.x(), v.y(), v.z(), 0.0]);
+        Vector3::new(x, y, z)
+    }
+
+    fn apply(&self, v: [f64; 4]) -> [f64; 4] {
+        self.rows
+            .map(|r| r.iter().zip(v.iter()).map(|(a, b)| a * b).sum())
+    }
+}
+
+impl Default for Matrix3 {
+    fn default() -> Self {
+        Matrix3::identity()
+    }
+}
+
+impl Default for Matrix4 {
+    fn default() -> Self {
+        Matrix4::identity()
+    }
+}
+
+impl Mul for Matrix3 {
+    type Output = Matrix3;
+
+    fn mul(self, rhs: Matrix3) -> Matrix3 {
+        Matrix3::new(std::array::from_fn(|r| {
+            std::array::from_fn(|c| (0..3).map(|k| self.rows[r][k] * rhs.rows[k][c]).sum())
+        }))
+    }
+}
+
+impl Mul<Vector3> for Matrix3 {
+    type Output = Vector3;
+
+    fn mul(self, v: Vector3) -> Vector3 {
+        Vector3::from(self.rows.map(|r| Vector3::from(r).dot(&v)))
+    }
+}
+
+impl Mul for Matrix4 {
+    type Output = Matrix4;
+
+    fn mul(self, rhs: Matrix4) -> Matrix4 {
+        Matrix4::new(std::array::from_fn(|r| {
+            std::array::from_fn(|c| (0..4).map(|k| self.rows[r][k] * rhs.rows[k][c]).sum())
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matrix_inverse_test() {
+        let m = Matrix3::new([[2.0, 0.0, 1.0], [1.0, 1.0, 0.0], [0.0, 3.0, 1.0]]);
+        assert_eq!(m.determinant(), 5.0);
+        let i = m.inverse().unwrap() * m;
+        for r in 0..3 {
+            for c in 0..3 {
+                let expected = if r == c { 1.0 } else { 0.0 };
+                assert!((i.get(r, c) - expected).abs() < 1e-12);
+            }
+        }
+        assert_eq!(Matrix3::diagonal(1.0, 0.0, 1.0).inverse(), None);
+
+        let m4 = Matrix4::new([
+            [1.0, 2.0, 0.0, 1.0],
+            [0.0, 1.0, 4.0, 0.0],
+            [3.0, 0.0, 1.0, 2.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ]);
+        let i4 = m4 * m4.inverse().unwrap();
+        for r in 0..4 {
+            for c in 0..4 {
+                let expected = if r == c { 1.0 } else { 0.0 };
+                assert!((i4.get(r, c) - expected).abs() < 1e-12);
+            }
+        }
+        assert!((m4.determinant() - 25.0).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,151 @@
+//! Text input for points
+//!
+//! Coordinates are separated by commas and/or whitespace, so `1 2 3`,
+//! `1,2,3` and `1, 2, 3` all parse to the same [`Point`].
+
+use std::io::BufRead;
+use std::str::FromStr;
+
+use crate::dims::{Point, Type};
+use crate::error::GeometryError;
+use crate::pointn::PointN;
+
+/// Parses the coordinates of `text`, checking their count against `expected`
+///
+/// Every failure is a [`GeometryError::Parse`] pointing at the offending token,
+/// or just past the end of the line when coordinates are missing.
+fn parse_coords(text: &str, line: usize, expected: Type) -> Result<Vec<f64>, GeometryError> {
+    let parse_error = |column: usize, message: String| GeometryError::Parse {
+        line,
+        column,
+        message,
+    };
+    let mut coords = Vec::new();
+    let mut start = None;
+    for (i, c) in text
+        .char_indices()
+        .chain(std::iter::once((text.len(), ',')))
+    {
+        if c == ',' || c.is_whitespace() {
+            if let Some(s) = start.take() {
+                let token = &text[s..i];
+                let column = text[..s].chars().count() + 1;
+                if coords.len() == expected.dimension() {
+                    return Err(parse_error(column, "too many coordinates".to_string()));
+                }
+                let v: f64 = token
+                    .parse()
+                    .map_err(|_| parse_error(column, format!("invalid coordinate `{}`", token)))?;
+                if !v.is_finite() {
+                    return Err(parse_error(
+                        column,
+                        format!("non-finite coordinate `{}`", token),
+                    ));
+                }
+                coords.push(v);
+            }
+        } else if start.is_none() {
+            start = Some(i);
+        }
+    }
+    if coords.len() < expected.dimension() {
+        let column = text.trim_end().chars().count() + 1;
+        return Err(parse_error(
+            column,
+            format!(
+                "expected {} coordinates, found {}",
+                expected.dimension(),
+                coords.len()
+            ),
+        ));
+    }
+    Ok(coords)
+}
+
+impl FromStr for Point {
+    type Err = GeometryError;
+
+    fn from_str(s: &str) -> Result<Self, GeometryError> {
+        let c = parse_coords(s, 1, Type::D3)?;
+        Ok(Point::new(c[0], c[1], c[2]))
+    }
+}
+
+impl<const D: usize> FromStr for PointN<D> {
+    type Err = GeometryError;
+
+    fn from_str(s: &str) -> Result<Self, GeometryError> {
+        let c = parse_coords(s, 1, Self::TYPE)?;
+        Ok(PointN::from_coords(std::array::from_fn(|i| c[i])))
+    }
+}
+
+/// Reads one point per line, skipping blank lines and lines starting with `#`
+pub fn read_points<R: BufRead>(reader: R) -> Result<Vec<Point>, GeometryError> {
+    let mut points = Vec::new();
+    for (i, line) in reader.lines().enumerate() {
+        let line = line?;
+        let text = line.trim_start();
+        if text.is_empty() || text.starts_with('#') {
+            continue;
+        }
+        let c = parse_coords(&line, i + 1, Type::D3)?;
+        points.push(Point::new(c[0], c[1], c[2]));
+    }
+    Ok(points)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pointn::Point2;
+
+    #[test]
+    fn parse_point_test() {
+        assert_eq!("1, 2.5 -3".parse(), Ok(Point::new(1.0, 2.5, -3.0)));
+        assert_eq!("4,5".parse(), Ok(Point2::new(4.0, 5.0)));
+        assert_eq!(
+            "1 2".parse::<Point>(),
+            Err(GeometryError::Parse {
+                line: 1,
+                column: 4,
+                message: "expected 3 coordinates, found 2".to_string()
+            })
+        );
+        assert!(matches!(
+            "1 2 3".parse::<Point2>(),
+            Err(GeometryError::Parse { column: 5, .. })
+        ));
+        assert_eq!(
+            "nan inf 0".parse::<Point>(),
+            Err(GeometryError::Parse {
+                line: 1,
+                column: 1,
+                message: "non-finite coordinate `nan`".to_string()
+            })
+        );
+        assert_eq!(
+            "1 x 3".parse::<Point>(),
+            Err(GeometryError::Parse {
+                line: 1,
+                column: 3,
+                message: "invalid coordinate `x`".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn read_points_test() {
+        let text = "# survey\n0 0 0\n\n1 2 3\n";
+        let points = read_points(text.as_bytes()).unwrap();
+        assert_eq!(points, [Point::origin(), Point::new(1.0, 2.0, 3.0)]);
+        match read_points("0 0 0\n1 2 3 4\n".as_bytes()) {
+            Err(GeometryError::Parse { line, column, .. }) => assert_eq!((line, column), (2, 7)),
+            other => panic!("unexpected {:?}", other),
+        }
+        match read_points("0 0 0\n1 2\n".as_bytes()) {
+            Err(GeometryError::Parse { line, column, .. }) => assert_eq!((line, column), (2, 4)),
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,240 @@
+use std::ops::Index;
+
+use crate::dimension::{D1, D2, D3};
+use crate::dims::{Dimensional, Point, Type};
+use crate::scalar::{Real, Scalar};
+
+/// PointN struct represents a point with `D` coordinates
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PointN<const D: usize, T = f64> {
+    coords: [T; D],
+}
+
+/// Point on a line
+pub type Point1<T = f64> = PointN<1, T>;
+/// Point in a plane
+pub type Point2<T = f64> = PointN<2, T>;
+/// Point in space stored as an array, convertible to and from [`Point`]
+pub type Point3<T = f64> = PointN<3, T>;
+
+impl<const D: usize, T> PointN<D, T> {
+    /// Dimension type matching the number of coordinates, fails to compile outside `1..=3`
+    pub const TYPE: Type = match Type::from_dimension(D) {
+        Some(t) => t,
+        None => panic!("PointN supports one to three dimensions"),
+    };
+
+    /// Creates a point from an array of coordinates
+    pub fn from_coords(coords: [T; D]) -> Self {
+        PointN { coords }
+    }
+
+    /// Coordinates in axis order
+    pub fn coords(&self) -> &[T; D] {
+        &self.coords
+    }
+
+    /// Consumes the point, returning its coordinates
+    pub fn into_coords(self) -> [T; D] {
+        self.coords
+    }
+}
+
+impl<const D: usize, T: Scalar> PointN<D, T> {
+    /// The point at the origin of the coordinate system
+    pub fn origin() -> Self {
+        PointN::from_coords(std::array::from_fn(|_| T::zero()))
+    }
+
+    /// Coordinate along `axis`
+    pub fn coord(&self, axis: usize) -> T {
+        self.coords[axis].clone()
+    }
+
+    /// Applies `f` to matching coordinates of two points
+    fn zip_with(&self, other: &Self, f: impl Fn(T, T) -> T) -> Self {
+        PointN::from_coords(std::array::from_fn(|i| f(self.coord(i), other.coord(i))))
+    }
+
+    /// Component-wise minimum of two points
+    pub fn min(&self, other: &Self) -> Self {
+        self.zip_with(other, T::min_of)
+    }
+
+    /// Component-wise maximum of two points
+    pub fn max(&self, other: &Self) -> Self {
+        self.zip_with(other, T::max_of)
+    }
+
+    /// Squared Euclidean distance, avoids the square root
+    pub fn distance_squared(&self, other: &Self) -> T {
+        (0..D).fold(T::zero(), |acc, i| {
+            let d = other.coord(i) - self.coord(i);
+            acc + d.clone() * d
+        })
+    }
+
+    /// Linear interpolation, `t = 0` gives `self` and `t = 1` gives `other`
+    pub fn lerp(&self, other: &Self, t: T) -> Self {
+        self.zip_with(other, |a, b| a.clone() + (b - a) * t.clone())
+    }
+
+    /// Converts every coordinate to the nearest `f64`
+    pub fn to_f64(&self) -> PointN<D> {
+        PointN::from_coords(std::array::from_fn(|i| self.coords[i].to_f64()))
+    }
+}
+
+impl<const D: usize, T: Real> PointN<D, T> {
+    /// Euclidean distance between two points
+    pub fn distance(&self, other: &Self) -> T {
+        self.distance_squared(other).sqrt()
+    }
+}
+
+impl<T: Scalar> PointN<1, T> {
+    /// Creates a point on a line
+    pub fn new(x: T) -> Self {
+        PointN::from_coords([x])
+    }
+
+    /// X coordinate
+    pub fn x(&self) -> T {
+        self.coord(0)
+    }
+}
+
+impl<T: Scalar> PointN<2, T> {
+    /// Creates a point in a plane
+    pub fn new(x: T, y: T) -> Self {
+        PointN::from_coords([x, y])
+    }
+
+    /// X coordinate
+    pub fn x(&self) -> T {
+        self.coord(0)
+    }
+
+    /// Y coordinate
+    pub fn y(&self) -> T {
+        self.coord(1)
+    }
+}
+
+impl<T: Scalar> PointN<3, T> {
+    /// Creates a point in space
+    pub fn new(x: T, y: T, z: T) -> Self {
+        PointN::from_coords([x, y, z])
+    }
+
+    /// X coordinate
+    pub fn x(&self) -> T {
+        self.coord(0)
+    }
+
+    /// Y coordinate
+    pub fn y(&self) -> T {
+        self.coord(1)
+    }
+
+    /// Z coordinate
+    pub fn z(&self) -> T {
+        self.coord(2)
+    }
+}
+
+impl<const D: usize, T: Scalar> Default for PointN<D, T> {
+    fn default() -> Self {
+        PointN::origin()
+    }
+}
+
+impl<const D: usize, T> From<[T; D]> for PointN<D, T> {
+    fn from(coords: [T; D]) -> Self {
+        PointN::from_coords(coords)
+    }
+}
+
+impl<T: Scalar> From<Point<T>> for PointN<3, T> {
+    fn from(p: Point<T>) -> Self {
+        PointN::from_coords(p.to_array())
+    }
+}
+
+impl<T: Scalar> From<PointN<3, T>> for Point<T> {
+    fn from(p: PointN<3, T>) -> Self {
+        Point::from(p.into_coords())
+    }
+}
+
+impl<const D: usize, T> Index<usize> for PointN<D, T> {
+    type Output = T;
+
+    fn index(&self, axis: usize) -> &T {
+        &self.coords[axis]
+    }
+}
+
+impl<T: Scalar> Dimensional for PointN<1, T> {
+    type Dim = D1;
+
+    fn bounding_box(&self) -> (PointN<1>, PointN<1>) {
+        (self.to_f64(), self.to_f64())
+    }
+
+    fn centroid(&self) -> PointN<1> {
+        self.to_f64()
+    }
+}
+
+impl<T: Scalar> Dimensional for PointN<2, T> {
+    type Dim = D2;
+
+    fn bounding_box(&self) -> (PointN<2>, PointN<2>) {
+        (self.to_f64(), self.to_f64())
+    }
+
+    fn centroid(&self) -> PointN<2> {
+        self.to_f64()
+    }
+}
+
+impl<T: Scalar> Dimensional for PointN<3, T> {
+    type Dim = D3;
+
+    fn bounding_box(&self) -> (Point, Point) {
+        (Point::from(self.to_f64()), Point::from(self.to_f64()))
+    }
+
+    fn centroid(&self) -> Point {
+        Point::from(self.to_f64())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dimension_type_test() {
+        assert_eq!(Point1::<f64>::TYPE, Type::D1);
+        assert_eq!(Point2::new(1.0, 2.0).dimensions(), Type::D2);
+        assert_eq!(Point3::<i64>::origin().dimensions(), Type::D3);
+        assert_eq!(Point::new(1.0, 2.0, 3.0).dimensions(), Type::D3);
+        assert_eq!(Type::D2.dimension(), 2);
+        assert_eq!(Type::from_dimension(4), None);
+    }
+
+    #[test]
+    fn point_conversion_test() {
+        let p = Point::new(1.0, 2.0, 3.0);
+        let q = Point3::from(p);
+        assert_eq!(q.z(), 3.0);
+        assert_eq!(Point::from(q), p);
+        let a = Point2::new(0.0, 0.0);
+        let b = Point2::new(3.0, 4.0);
+        assert_eq!(a.distance(&b), 5.0);
+        assert_eq!(a.lerp(&b, 0.5), Point2::new(1.5, 2.0));
+        assert_eq!(a.max(&b)[1], 4.0);
+    }
+}
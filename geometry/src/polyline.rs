@@ -0,0 +1,307 @@
+use crate::crs::Reprojectable;
+use crate::dimension::D3;
+use crate::dims::{Dimensional, Point, Type};
+use crate::error::GeometryError;
+use crate::linear::Segment;
+use crate::transform::{Affine3, Transformable};
+use crate::vector::Vector3;
+
+/// Polyline struct represents a chain of straight segments through two or more vertices
+///
+/// Cumulative arc lengths are cached, so lookups by distance are logarithmic
+/// in the number of vertices. Repeated vertices are allowed and give
+/// zero-length segments.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polyline {
+    vertices: Vec<Point>,
+    distances: Vec<f64>,
+}
+
+impl Polyline {
+    /// Polyline through `vertices` in order, failing for fewer than two or non-finite vertices
+    pub fn new(vertices: Vec<Point>) -> Result<Self, GeometryError> {
+        if vertices.len() < 2 {
+            return Err(GeometryError::InvalidTopology(format!(
+                "a polyline needs at least two vertices, got {}",
+                vertices.len()
+            )));
+        }
+        if vertices
+            .iter()
+            .flat_map(|p| p.to_array())
+            .any(|v| !v.is_finite())
+        {
+            return Err(GeometryError::NonFinite);
+        }
+        Ok(Polyline::from_vertices(vertices))
+    }
+
+    fn from_vertices(vertices: Vec<Point>) -> Self {
+        let mut total = 0.0;
+        let distances = std::iter::once(0.0)
+            .chain(vertices.windows(2).map(|w| {
+                total += w[0].distance(&w[1]);
+                total
+            }))
+            .collect();
+        Polyline {
+            vertices,
+            distances,
+        }
+    }
+
+    /// Vertices in order
+    pub fn vertices(&self) -> &[Point] {
+        &self.vertices
+    }
+
+    /// Segments between consecutive vertices
+    pub fn segments(&self) -> impl Iterator<Item = Segment> + '_ {
+        self.vertices
+            .windows(2)
+            .map(|w| Segment::new_unchecked(w[0], w[1]))
+    }
+
+    /// True when the last vertex repeats the first
+    pub fn is_closed(&self) -> bool {
+        self.vertices.first() == self.vertices.last()
+    }
+
+    /// Total length along the segments
+    pub fn length(&self) -> f64 {
+        self.distances[self.distances.len() - 1]
+    }
+
+    /// Arc length from the start to each vertex
+    pub fn vertex_distances(&self) -> &[f64] {
+        &self.distances
+    }
+
+    /// Segment index and interpolation parameter at `distance`, clamped to the polyline
+    ///
+    /// A NaN distance locates the start.
+    fn locate(&self, distance: f64) -> (usize, f64) {
+        let d = if distance.is_nan() {
+            0.0
+        } else {
+            distance.clamp(0.0, self.length())
+        };
+        let last = self.vertices.len() - 2;
+        let i = self
+            .distances
+            .partition_point(|&x| x <= d)
+            .saturating_sub(1)
+            .min(last);
+        let span = self.distances[i + 1] - self.distances[i];
+        let t = if span > 0.0 {
+            ((d - self.distances[i]) / span).min(1.0)
+        } else {
+            0.0
+        };
+        (i, t)
+    }
+
+    /// Point at arc length `distance` from the start, clamped to the end points
+    ///
+    /// A NaN distance gives the start point.
+    pub fn point_at_distance(&self, distance: f64) -> Point {
+        let (i, t) = self.locate(distance);
+        self.vertices[i].lerp(&self.vertices[i + 1], t)
+    }
+
+    /// Point at arc length parameter `t`, `t = 0` gives the start and `t = 1` the end
+    pub fn point_at(&self, t: f64) -> Point {
+        self.point_at_distance(t * self.length())
+    }
+
+    /// Closest point and its arc length, ties go to the earliest segment
+    fn nearest(&self, p: &Point) -> (Point, f64) {
+        let mut best = (self.vertices[0], 0.0, f64::INFINITY);
+        for (i, segment) in self.segments().enumerate() {
+            let q = segment.closest_point(p);
+            let d = q.distance_squared(p);
+            if d < best.2 {
+                best = (q, self.distances[i] + self.vertices[i].distance(&q), d);
+            }
+        }
+        (best.0, best.1)
+    }
+
+    /// Point on the polyline closest to `p`
+    pub fn closest_point(&self, p: &Point) -> Point {
+        self.nearest(p).0
+    }
+
+    /// Arc length from the start to the point closest to `p`
+    pub fn distance_along(&self, p: &Point) -> f64 {
+        self.nearest(p).1
+    }
+
+    /// Distance from `p` to the polyline
+    pub fn distance(&self, p: &Point) -> f64 {
+        self.closest_point(p).distance(p)
+    }
+
+    /// Polyline of `count` vertices evenly spaced by arc length, keeping both end points
+    pub fn resampled(&self, count: usize) -> Result<Polyline, GeometryError> {
+        if count < 2 {
+            return Err(GeometryError::InvalidTopology(format!(
+                "a polyline needs at least two vertices, got {}",
+                count
+            )));
+        }
+        let step = self.length() / (count - 1) as f64;
+        let mut vertices: Vec<_> = (0..count - 1)
+            .map(|k| self.point_at_distance(k as f64 * step))
+            .collect();
+        vertices.push(self.vertices[self.vertices.len() - 1]);
+        Ok(Polyline::from_vertices(vertices))
+    }
+
+    /// Splits at arc length `distance`, clamped to the polyline
+    ///
+    /// Splitting at an end point leaves a zero-length polyline on that side,
+    /// a NaN distance splits at the start.
+    pub fn split_at(&self, distance: f64) -> (Polyline, Polyline) {
+        let (i, t) = self.locate(distance);
+        let cut = self.vertices[i].lerp(&self.vertices[i + 1], t);
+        let mut head = self.vertices[..=i].to_vec();
+        if head.len() == 1 || cut != head[i] {
+            head.push(cut);
+        }
+        let mut tail = vec![cut];
+        let rest = &self.vertices[i + 1..];
+        let skip = usize::from(rest.len() > 1 && rest[0] == cut);
+        tail.extend_from_slice(&rest[skip..]);
+        (Polyline::from_vertices(head), Polyline::from_vertices(tail))
+    }
+
+    /// Vertices in the opposite order
+    pub fn reversed(&self) -> Polyline {
+        Polyline::from_vertices(self.vertices.iter().rev().copied().collect())
+    }
+}
+
+impl Dimensional for Polyline {
+    type Dim = D3;
+
+    fn dimensions(&self) -> Type {
+        Type::D1
+    }
+
+    fn measure(&self) -> f64 {
+        self.length()
+    }
+
+    fn bounding_box(&self) -> (Point, Point) {
+        let first = self.vertices[0];
+        self.vertices
+            .iter()
+            .fold((first, first), |(lo, hi), p| (lo.min(p), hi.max(p)))
+    }
+
+    /// Center of mass of the segments, the first vertex when the length is zero
+    fn centroid(&self) -> Point {
+        let length = self.length();
+        if length == 0.0 {
+            return self.vertices[0];
+        }
+        let sum = self.segments().fold(Vector3::zero(), |acc, s| {
+            acc + s.midpoint().to_vector() * s.length()
+        });
+        Point::origin() + sum / length
+    }
+}
+
+impl Transformable for Polyline {
+    fn transform(&self, t: &Affine3) -> Self {
+        Polyline::from_vertices(self.vertices.iter().map(|p| t.apply_point(p)).collect())
+    }
+}
+
+impl Reprojectable for Polyline {
+    /// Maps each vertex and recomputes the lengths in the target coordinates
+    fn map_points(
+        &self,
+        f: &mut dyn FnMut(&Point) -> Result<Point, GeometryError>,
+    ) -> Result<Self, GeometryError> {
+        Polyline::new(self.vertices.map_points(f)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn staircase() -> Polyline {
+        Polyline::new(vec![
+            Point::origin(),
+            Point::new(3.0, 0.0, 0.0),
+            Point::new(3.0, 4.0, 0.0),
+            Point::new(3.0, 4.0, 5.0),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn length_test() {
+        let line = staircase();
+        assert_eq!(line.length(), 12.0);
+        assert!(matches!(line.dimensions(), Type::D1));
+        assert_eq!(line.point_at_distance(5.0), Point::new(3.0, 2.0, 0.0));
+        assert_eq!(line.point_at(1.0), Point::new(3.0, 4.0, 5.0));
+        assert_eq!(line.point_at_distance(-1.0), Point::origin());
+        assert_eq!(line.point_at_distance(f64::NAN), Point::origin());
+        assert_eq!(line.point_at(f64::NAN), Point::origin());
+        crate::assert_approx_eq!(
+            line.centroid(),
+            Point::new(31.5 / 12.0, 28.0 / 12.0, 12.5 / 12.0)
+        );
+        assert!(matches!(
+            Polyline::new(vec![Point::origin()]),
+            Err(GeometryError::InvalidTopology(_))
+        ));
+    }
+
+    #[test]
+    fn closest_and_split_test() {
+        let line = staircase();
+        let p = Point::new(5.0, 1.0, 0.0);
+        assert_eq!(line.closest_point(&p), Point::new(3.0, 1.0, 0.0));
+        assert_eq!(line.distance_along(&p), 4.0);
+        assert_eq!(line.distance(&p), 2.0);
+        let (head, tail) = line.split_at(5.0);
+        assert_eq!(head.length(), 5.0);
+        assert_eq!(tail.length(), 7.0);
+        assert_eq!(tail.vertices()[0], Point::new(3.0, 2.0, 0.0));
+        let (head, tail) = line.split_at(3.0);
+        assert_eq!(head.vertices().len(), 2);
+        assert_eq!(tail.vertices().len(), 3);
+        let (head, _) = line.split_at(0.0);
+        assert_eq!(head.length(), 0.0);
+        let (head, tail) = line.split_at(f64::NAN);
+        assert_eq!(head.length(), 0.0);
+        assert_eq!(tail.length(), 12.0);
+    }
+
+    #[test]
+    fn resample_test() {
+        let line = staircase();
+        let even = line.resampled(5).unwrap();
+        assert_eq!(
+            even.vertices(),
+            &[
+                Point::origin(),
+                Point::new(3.0, 0.0, 0.0),
+                Point::new(3.0, 3.0, 0.0),
+                Point::new(3.0, 4.0, 2.0),
+                Point::new(3.0, 4.0, 5.0),
+            ]
+        );
+        assert!(line.resampled(1).is_err());
+        assert_eq!(
+            line.reversed().point_at_distance(5.0),
+            Point::new(3.0, 4.0, 0.0)
+        );
+    }
+}
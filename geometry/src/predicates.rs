@@ -0,0 +1,352 @@
+//! Adaptive precision geometric predicates after Shewchuk
+//!
+//! Each predicate first evaluates its determinant in plain floating point
+//! together with a bound on the rounding error. Only when the sign is not
+//! certain is the determinant recomputed exactly using floating point
+//! expansions, so the returned sign is always correct for finite input.
+
+use std::cmp::Ordering;
+
+use crate::dims::Point;
+use crate::pointn::Point2;
+
+/// Half an ulp of one, the relative rounding error of one operation
+const EPSILON: f64 = f64::EPSILON / 2.0;
+const ORIENT2D_BOUND: f64 = (3.0 + 16.0 * EPSILON) * EPSILON;
+const ORIENT3D_BOUND: f64 = (7.0 + 56.0 * EPSILON) * EPSILON;
+const INCIRCLE_BOUND: f64 = (10.0 + 96.0 * EPSILON) * EPSILON;
+const INSPHERE_BOUND: f64 = (16.0 + 224.0 * EPSILON) * EPSILON;
+
+/// Orientation of `c` relative to the directed line `a -> b`
+///
+/// `Greater` when counter-clockwise, `Less` when clockwise and `Equal` when collinear.
+pub fn orient2d(a: &Point2, b: &Point2, c: &Point2) -> Ordering {
+    let (acx, acy) = (a.x() - c.x(), a.y() - c.y());
+    let (bcx, bcy) = (b.x() - c.x(), b.y() - c.y());
+    let left = acx * bcy;
+    let right = acy * bcx;
+    let det = left - right;
+    let bound = ORIENT2D_BOUND * (left.abs() + right.abs());
+    if det.abs() > bound {
+        return sign(det);
+    }
+    let rows = [[a.x(), a.y()], [b.x(), b.y()], [c.x(), c.y()]];
+    sign_of(&exact_det(&lift_rows(&rows, false)))
+}
+
+/// Orientation of `d` relative to the plane through `a`, `b` and `c`
+///
+/// `Greater` when `d` lies below the plane, where `a`, `b`, `c` appear
+/// counter-clockwise from above, `Less` when above and `Equal` when coplanar.
+pub fn orient3d(a: &Point, b: &Point, c: &Point, d: &Point) -> Ordering {
+    let ad = *a - *d;
+    let bd = *b - *d;
+    let cd = *c - *d;
+    let terms = [
+        ad.x() * (bd.y() * cd.z() - bd.z() * cd.y()),
+        bd.x() * (cd.y() * ad.z() - cd.z() * ad.y()),
+        cd.x() * (ad.y() * bd.z() - ad.z() * bd.y()),
+    ];
+    let det: f64 = terms.iter().sum();
+    let permanent = ad.x().abs() * (bd.y() * cd.z()).abs().max((bd.z() * cd.y()).abs()) * 2.0
+        + bd.x().abs() * (cd.y() * ad.z()).abs().max((cd.z() * ad.y()).abs()) * 2.0
+        + cd.x().abs() * (ad.y() * bd.z()).abs().max((ad.z() * bd.y()).abs()) * 2.0;
+    if det.abs() > ORIENT3D_BOUND * permanent {
+        return sign(det);
+    }
+    let rows = [a, b, c, d].map(|p| p.to_array());
+    sign_of(&exact_det(&lift_rows(&rows, false)))
+}
+
+/// Position of `d` relative to the circle through `a`, `b` and `c`
+///
+/// With `a`, `b`, `c` counter-clockwise, `Greater` means inside, `Less` outside
+/// and `Equal` cocircular; the sign flips for clockwise input.
+pub fn incircle(a: &Point2, b: &Point2, c: &Point2, d: &Point2) -> Ordering {
+    let rel = [a, b, c].map(|p| [p.x() - d.x(), p.y() - d.y()]);
+    let lift = rel.map(|[x, y]| x * x + y * y);
+    let minor = |i: usize, j: usize| rel[i][0] * rel[j][1] - rel[j][0] * rel[i][1];
+    let det = lift[0] * minor(1, 2) + lift[1] * minor(2, 0) + lift[2] * minor(0, 1);
+    let abs_minor =
+        |i: usize, j: usize| (rel[i][0] * rel[j][1]).abs() + (rel[j][0] * rel[i][1]).abs();
+    let permanent =
+        lift[0] * abs_minor(1, 2) + lift[1] * abs_minor(2, 0) + lift[2] * abs_minor(0, 1);
+    if det.abs() > INCIRCLE_BOUND * permanent {
+        return sign(det);
+    }
+    let rows = [a, b, c, d].map(|p| [p.x(), p.y()]);
+    sign_of(&exact_det(&lift_rows(&rows, true)))
+}
+
+/// Position of `e` relative to the sphere through `a`, `b`, `c` and `d`
+///
+/// With `orient3d(a, b, c, d)` positive, `Greater` means inside, `Less` outside
+/// and `Equal` cospherical; the sign flips for negatively oriented input.
+pub fn insphere(a: &Point, b: &Point, c: &Point, d: &Point, e: &Point) -> Ordering {
+    let rel = [a, b, c, d].map(|p| (*p - *e).to_array());
+    let lift = rel.map(|[x, y, z]| x * x + y * y + z * z);
+    let det3 = |i: usize, j: usize, k: usize, abs: bool| {
+        let f = |v: f64| if abs { v.abs() } else { v };
+        let [p, q, r] = [rel[i], rel[j], rel[k]];
+        f(p[0]) * (f(q[1] * r[2]) + if abs { f(q[2] * r[1]) } else { -q[2] * r[1] })
+            + f(q[0]) * (f(r[1] * p[2]) + if abs { f(r[2] * p[1]) } else { -r[2] * p[1] })
+            + f(r[0]) * (f(p[1] * q[2]) + if abs { f(p[2] * q[1]) } else { -p[2] * q[1] })
+    };
+    let det = -lift[0] * det3(1, 2, 3, false) + lift[1] * det3(2, 3, 0, false)
+        - lift[2] * det3(3, 0, 1, false)
+        + lift[3] * det3(0, 1, 2, false);
+    let permanent = lift[0] * det3(1, 2, 3, true)
+        + lift[1] * det3(2, 3, 0, true)
+        + lift[2] * det3(3, 0, 1, true)
+        + lift[3] * det3(0, 1, 2, true);
+    if det.abs() > INSPHERE_BOUND * permanent {
+        return sign(det);
+    }
+    let rows = [a, b, c, d, e].map(|p| p.to_array());
+    sign_of(&exact_det(&lift_rows(&rows, true)))
+}
+
+/// Collinearity of three points in space, exact for finite input
+pub fn collinear3d(a: &Point, b: &Point, c: &Point) -> bool {
+    let project = |p: &Point, i: usize, j: usize| {
+        let c = p.to_array();
+        Point2::new(c[i], c[j])
+    };
+    [(0, 1), (1, 2), (2, 0)].iter().all(|&(i, j)| {
+        orient2d(&project(a, i, j), &project(b, i, j), &project(c, i, j)) == Ordering::Equal
+    })
+}
+
+/// Collinearity of three integer points, exact over the whole `i64` range
+///
+/// Coordinate differences fit in `i128` and each cross product term is
+/// compared by sign and `u128` magnitude, so nothing can overflow.
+pub fn collinear_int(a: [i64; 3], b: [i64; 3], c: [i64; 3]) -> bool {
+    let u = [0, 1, 2].map(|i| i128::from(b[i]) - i128::from(a[i]));
+    let v = [0, 1, 2].map(|i| i128::from(c[i]) - i128::from(a[i]));
+    let equal_products = |p: i128, q: i128, r: i128, s: i128| {
+        (p.signum() * q.signum() == r.signum() * s.signum())
+            && p.unsigned_abs() * q.unsigned_abs() == r.unsigned_abs() * s.unsigned_abs()
+    };
+    [(0, 1), (1, 2), (2, 0)]
+        .iter()
+        .all(|&(i, j)| equal_products(u[i], v[j], u[j], v[i]))
+}
+
+fn sign(v: f64) -> Ordering {
+    v.partial_cmp(&0.0).unwrap_or(Ordering::Equal)
+}
+
+/// Exact expansion representing a single float
+type Expansion = Vec<f64>;
+
+/// Builds the `(n + 1) x (n + 1)` matrix `[p, (|p|^2), 1]` whose determinant is the predicate
+///
+/// Rows hold exact expansions; the lifted column is included when `lifted`.
+fn lift_rows<const N: usize>(rows: &[[f64; N]], lifted: bool) -> Vec<Vec<Expansion>> {
+    rows.iter()
+        .map(|r| {
+            let mut row: Vec<Expansion> = r.iter().map(|&v| vec![v]).collect();
+            if lifted {
+                let sq = r.iter().fold(Vec::new(), |acc, &v| {
+                    expansion_sum(&acc, &two_product_expansion(v, v))
+                });
+                row.push(sq);
+            }
+            row.push(vec![1.0]);
+            row
+        })
+        .collect()
+}
+
+/// Exact determinant by Laplace expansion along the first column
+fn exact_det(m: &[Vec<Expansion>]) -> Expansion {
+    if m.len() == 1 {
+        return m[0][0].clone();
+    }
+    let mut total = Vec::new();
+    for (i, row) in m.iter().enumerate() {
+        let minor: Vec<Vec<Expansion>> = m
+            .iter()
+            .enumerate()
+            .filter(|&(k, _)| k != i)
+            .map(|(_, r)| r[1..].to_vec())
+            .collect();
+        let mut term = expansion_product(&row[0], &exact_det(&minor));
+        if i % 2 == 1 {
+            term.iter_mut().for_each(|v| *v = -*v);
+        }
+        total = expansion_sum(&total, &term);
+    }
+    total
+}
+
+/// Sign of an expansion, the sign of its largest magnitude component
+fn sign_of(e: &Expansion) -> Ordering {
+    e.iter()
+        .rev()
+        .find(|v| **v != 0.0)
+        .map_or(Ordering::Equal, |v| sign(*v))
+}
+
+/// `a + b` as an exact pair `(sum, error)`
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let x = a + b;
+    let bv = x - a;
+    let av = x - bv;
+    (x, (a - av) + (b - bv))
+}
+
+/// `a * b` as an exact pair `(product, error)`
+fn two_product(a: f64, b: f64) -> (f64, f64) {
+    let x = a * b;
+    (x, a.mul_add(b, -x))
+}
+
+fn two_product_expansion(a: f64, b: f64) -> Expansion {
+    let (x, y) = two_product(a, b);
+    vec![y, x]
+}
+
+/// Adds a float to an expansion, keeping components in increasing magnitude
+fn grow_expansion(e: &[f64], b: f64) -> Expansion {
+    let mut q = b;
+    let mut h = Vec::with_capacity(e.len() + 1);
+    for &v in e {
+        let (sum, err) = two_sum(q, v);
+        if err != 0.0 {
+            h.push(err);
+        }
+        q = sum;
+    }
+    if q != 0.0 || h.is_empty() {
+        h.push(q);
+    }
+    h
+}
+
+fn expansion_sum(e: &[f64], f: &[f64]) -> Expansion {
+    f.iter().fold(e.to_vec(), |acc, &v| grow_expansion(&acc, v))
+}
+
+fn scale_expansion(e: &[f64], b: f64) -> Expansion {
+    e.iter().fold(Vec::new(), |acc, &v| {
+        expansion_sum(&acc, &two_product_expansion(v, b))
+    })
+}
+
+fn expansion_product(e: &[f64], f: &[f64]) -> Expansion {
+    f.iter().fold(Vec::new(), |acc, &v| {
+        expansion_sum(&acc, &scale_expansion(e, v))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn orient2d_near_collinear_test() {
+        let a = Point2::new(12.0, 12.0);
+        let b = Point2::new(24.0, 24.0);
+        let mut below = 0;
+        let mut above = 0;
+        for i in 0..64 {
+            for j in 0..64 {
+                let x = 0.5 + i as f64 * f64::EPSILON;
+                let y = 0.5 + j as f64 * f64::EPSILON;
+                let c = Point2::new(x, y);
+                let expected = y.partial_cmp(&x).unwrap();
+                assert_eq!(orient2d(&a, &b, &c), expected);
+                below += (expected == Ordering::Less) as i32;
+                above += (expected == Ordering::Greater) as i32;
+            }
+        }
+        assert!(below > 0 && above > 0);
+    }
+
+    #[test]
+    fn orient3d_test() {
+        let a = Point::new(0.0, 0.0, 0.0);
+        let b = Point::new(1.0, 0.0, 0.0);
+        let c = Point::new(0.0, 1.0, 0.0);
+        assert_eq!(
+            orient3d(&a, &b, &c, &Point::new(0.3, 0.3, -1.0)),
+            Ordering::Greater
+        );
+        assert_eq!(
+            orient3d(&a, &b, &c, &Point::new(0.3, 0.3, 1.0)),
+            Ordering::Less
+        );
+        let tiny = Point::new(0.1, 0.2, 1e-300);
+        assert_eq!(orient3d(&a, &b, &c, &tiny), Ordering::Less);
+        let planar = Point::new(0.1 + 0.2, 0.3, 0.0);
+        assert_eq!(orient3d(&a, &b, &c, &planar), Ordering::Equal);
+    }
+
+    #[test]
+    fn incircle_and_insphere_test() {
+        let a = Point2::new(1.0, 0.0);
+        let b = Point2::new(0.0, 1.0);
+        let c = Point2::new(-1.0, 0.0);
+        assert_eq!(
+            incircle(&a, &b, &c, &Point2::new(0.0, 0.0)),
+            Ordering::Greater
+        );
+        assert_eq!(
+            incircle(&a, &b, &c, &Point2::new(0.0, -1.0)),
+            Ordering::Equal
+        );
+        assert_eq!(
+            incircle(&a, &b, &c, &Point2::new(0.0, -1.0 - 1e-15)),
+            Ordering::Less
+        );
+
+        let p = [
+            Point::new(1.0, 0.0, 0.0),
+            Point::new(0.0, 1.0, 0.0),
+            Point::new(-1.0, 0.0, 0.0),
+            Point::new(0.0, 0.0, -1.0),
+        ];
+        assert_eq!(orient3d(&p[0], &p[1], &p[2], &p[3]), Ordering::Greater);
+        assert_eq!(
+            insphere(&p[0], &p[1], &p[2], &p[3], &Point::origin()),
+            Ordering::Greater
+        );
+        assert_eq!(
+            insphere(&p[0], &p[1], &p[2], &p[3], &Point::new(0.0, 0.0, 1.0)),
+            Ordering::Equal
+        );
+        assert_eq!(
+            insphere(&p[0], &p[1], &p[2], &p[3], &Point::new(0.0, 2.0, 0.0)),
+            Ordering::Less
+        );
+    }
+
+    #[test]
+    fn collinear3d_test() {
+        let a = Point::new(0.1, 0.2, 0.3);
+        let b = Point::new(0.2, 0.4, 0.6);
+        let c = Point::new(0.3, 0.6, 0.9000000000000001);
+        assert!(!collinear3d(&a, &b, &c));
+        assert!(collinear3d(&a, &b, &Point::new(0.4, 0.8, 1.2)));
+    }
+
+    #[test]
+    fn collinear_int_test() {
+        let big = 4_000_000_000;
+        assert!(collinear_int(
+            [0, 0, 0],
+            [big, big, big],
+            [-big, -big, -big]
+        ));
+        assert!(!collinear_int(
+            [0, 0, 0],
+            [big, big, big],
+            [-big, -big, 1 - big]
+        ));
+        let (min, max) = (i64::MIN, i64::MAX);
+        assert!(collinear_int([min, min, min], [max, max, max], [0, 0, 0]));
+        assert!(!collinear_int([min, 0, 0], [max, 0, 0], [0, 1, 0]));
+    }
+}
@@ -0,0 +1,317 @@
+//! Map projections from geodetic coordinates to planar points
+//!
+//! Projected points are [`Point2`]s in meters with x pointing east and y
+//! north. Altitude is not projected, inverse projections return zero altitude.
+
+use std::f64::consts::FRAC_PI_4;
+
+use crate::error::GeometryError;
+use crate::geodetic::{Ellipsoid, Geodetic};
+use crate::pointn::Point2;
+
+/// Projection trait supplies forward and inverse maps between the ellipsoid and the plane
+pub trait Projection {
+    /// Planar position of a geodetic coordinate
+    fn project(&self, g: &Geodetic) -> Point2;
+
+    /// Geodetic coordinate of a planar position, with zero altitude
+    fn unproject(&self, p: &Point2) -> Geodetic;
+}
+
+/// Longitude difference in degrees wrapped to `[-180, 180)`
+fn wrap_longitude(lon: f64) -> f64 {
+    (lon + 180.0).rem_euclid(360.0) - 180.0
+}
+
+/// WebMercator struct represents the spherical Mercator projection used by web maps
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct WebMercator;
+
+impl WebMercator {
+    /// Sphere radius in meters, the WGS84 semi-major axis
+    pub const RADIUS: f64 = 6_378_137.0;
+    /// Latitude in degrees where the square map ends, latitudes beyond are clamped
+    pub const MAX_LATITUDE: f64 = 85.051_128_779_806_59;
+}
+
+impl Projection for WebMercator {
+    fn project(&self, g: &Geodetic) -> Point2 {
+        let lat = g
+            .latitude()
+            .clamp(-Self::MAX_LATITUDE, Self::MAX_LATITUDE)
+            .to_radians();
+        let lon = wrap_longitude(g.longitude()).to_radians();
+        Point2::new(
+            Self::RADIUS * lon,
+            Self::RADIUS * (FRAC_PI_4 + lat / 2.0).tan().ln(),
+        )
+    }
+
+    fn unproject(&self, p: &Point2) -> Geodetic {
+        let lat = 2.0 * (p.y() / Self::RADIUS).exp().atan() - 2.0 * FRAC_PI_4;
+        Geodetic::new(lat.to_degrees(), (p.x() / Self::RADIUS).to_degrees(), 0.0)
+    }
+}
+
+/// Equirectangular struct represents the plate carrée family with a chosen standard parallel
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Equirectangular {
+    standard_parallel: f64,
+    central_meridian: f64,
+    radius: f64,
+}
+
+impl Equirectangular {
+    /// Projection true to scale along `standard_parallel`, in degrees, centered on Greenwich
+    pub fn new(standard_parallel: f64) -> Self {
+        Equirectangular {
+            standard_parallel,
+            central_meridian: 0.0,
+            radius: Ellipsoid::WGS84.semi_major(),
+        }
+    }
+
+    /// Same projection centered on `central_meridian`, in degrees
+    pub fn with_central_meridian(self, central_meridian: f64) -> Self {
+        Equirectangular {
+            central_meridian,
+            ..self
+        }
+    }
+}
+
+impl Default for Equirectangular {
+    fn default() -> Self {
+        Equirectangular::new(0.0)
+    }
+}
+
+impl Projection for Equirectangular {
+    fn project(&self, g: &Geodetic) -> Point2 {
+        let lon = wrap_longitude(g.longitude() - self.central_meridian).to_radians();
+        Point2::new(
+            self.radius * lon * self.standard_parallel.to_radians().cos(),
+            self.radius * g.latitude().to_radians(),
+        )
+    }
+
+    fn unproject(&self, p: &Point2) -> Geodetic {
+        let lon = p.x() / (self.radius * self.standard_parallel.to_radians().cos());
+        Geodetic::new(
+            (p.y() / self.radius).to_degrees(),
+            wrap_longitude(lon.to_degrees() + self.central_meridian),
+            0.0,
+        )
+    }
+}
+
+/// Utm struct represents one zone of the Universal Transverse Mercator projection on WGS84
+///
+/// Uses the Krüger series to fourth order, accurate to well below a
+/// millimeter inside the zone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Utm {
+    zone: u8,
+    north: bool,
+}
+
+const UTM_SCALE: f64 = 0.9996;
+const UTM_FALSE_EASTING: f64 = 500_000.0;
+const UTM_FALSE_NORTHING: f64 = 10_000_000.0;
+
+/// Series coefficients in the third flattening `n` of WGS84
+struct KruegerSeries {
+    rectifying_radius: f64,
+    alpha: [f64; 4],
+    beta: [f64; 4],
+    delta: [f64; 4],
+}
+
+impl KruegerSeries {
+    fn wgs84() -> Self {
+        let f = Ellipsoid::WGS84.flattening();
+        let n = f / (2.0 - f);
+        let (n2, n3, n4) = (n * n, n * n * n, n * n * n * n);
+        KruegerSeries {
+            rectifying_radius: Ellipsoid::WGS84.semi_major() / (1.0 + n)
+                * (1.0 + n2 / 4.0 + n4 / 64.0),
+            alpha: [
+                n / 2.0 - 2.0 * n2 / 3.0 + 5.0 * n3 / 16.0 + 41.0 * n4 / 180.0,
+                13.0 * n2 / 48.0 - 3.0 * n3 / 5.0 + 557.0 * n4 / 1440.0,
+                61.0 * n3 / 240.0 - 103.0 * n4 / 140.0,
+                49561.0 * n4 / 161_280.0,
+            ],
+            beta: [
+                n / 2.0 - 2.0 * n2 / 3.0 + 37.0 * n3 / 96.0 - n4 / 360.0,
+                n2 / 48.0 + n3 / 15.0 - 437.0 * n4 / 1440.0,
+                17.0 * n3 / 480.0 - 37.0 * n4 / 840.0,
+                4397.0 * n4 / 161_280.0,
+            ],
+            delta: [
+                2.0 * n - 2.0 * n2 / 3.0 - 2.0 * n3 + 116.0 * n4 / 45.0,
+                7.0 * n2 / 3.0 - 8.0 * n3 / 5.0 - 227.0 * n4 / 45.0,
+                56.0 * n3 / 15.0 - 136.0 * n4 / 35.0,
+                4279.0 * n4 / 630.0,
+            ],
+        }
+    }
+}
+
+impl Utm {
+    /// Zone `1..=60` in the northern or southern hemisphere, failing for other zone numbers
+    pub fn new(zone: u8, north: bool) -> Result<Self, GeometryError> {
+        if (1..=60).contains(&zone) {
+            Ok(Utm { zone, north })
+        } else {
+            Err(GeometryError::InvalidZone(zone))
+        }
+    }
+
+    /// Zone containing a coordinate, including the Norway and Svalbard exceptions
+    pub fn for_geodetic(g: &Geodetic) -> Self {
+        let (lat, lon) = (g.latitude(), wrap_longitude(g.longitude()));
+        let mut zone = (((lon + 180.0) / 6.0).floor() as u8).min(59) + 1;
+        if (56.0..64.0).contains(&lat) && (3.0..12.0).contains(&lon) {
+            zone = 32;
+        } else if (72.0..=84.0).contains(&lat) && (0.0..42.0).contains(&lon) {
+            zone = match lon {
+                l if l < 9.0 => 31,
+                l if l < 21.0 => 33,
+                l if l < 33.0 => 35,
+                _ => 37,
+            };
+        }
+        Utm {
+            zone,
+            north: lat >= 0.0,
+        }
+    }
+
+    /// Zone number
+    pub fn zone(&self) -> u8 {
+        self.zone
+    }
+
+    /// True for the northern hemisphere
+    pub fn is_north(&self) -> bool {
+        self.north
+    }
+
+    /// Longitude of the zone's central meridian in degrees
+    pub fn central_meridian(&self) -> f64 {
+        f64::from(self.zone) * 6.0 - 183.0
+    }
+
+    fn false_northing(&self) -> f64 {
+        if self.north {
+            0.0
+        } else {
+            UTM_FALSE_NORTHING
+        }
+    }
+}
+
+impl Projection for Utm {
+    fn project(&self, g: &Geodetic) -> Point2 {
+        let s = KruegerSeries::wgs84();
+        let e = Ellipsoid::WGS84.eccentricity_squared().sqrt();
+        let lat = g.latitude().to_radians();
+        let lon = wrap_longitude(g.longitude() - self.central_meridian()).to_radians();
+        let t = (lat.sin().atanh() - e * (e * lat.sin()).atanh()).sinh();
+        let xi0 = t.atan2(lon.cos());
+        let eta0 = (lon.sin() / (1.0 + t * t).sqrt()).atanh();
+        let (mut xi, mut eta) = (xi0, eta0);
+        for (j, a) in s.alpha.iter().enumerate() {
+            let k = 2.0 * (j + 1) as f64;
+            xi += a * (k * xi0).sin() * (k * eta0).cosh();
+            eta += a * (k * xi0).cos() * (k * eta0).sinh();
+        }
+        let scale = UTM_SCALE * s.rectifying_radius;
+        Point2::new(
+            UTM_FALSE_EASTING + scale * eta,
+            self.false_northing() + scale * xi,
+        )
+    }
+
+    fn unproject(&self, p: &Point2) -> Geodetic {
+        let s = KruegerSeries::wgs84();
+        let scale = UTM_SCALE * s.rectifying_radius;
+        let xi = (p.y() - self.false_northing()) / scale;
+        let eta = (p.x() - UTM_FALSE_EASTING) / scale;
+        let (mut xi0, mut eta0) = (xi, eta);
+        for (j, b) in s.beta.iter().enumerate() {
+            let k = 2.0 * (j + 1) as f64;
+            xi0 -= b * (k * xi).sin() * (k * eta).cosh();
+            eta0 -= b * (k * xi).cos() * (k * eta).sinh();
+        }
+        let chi = (xi0.sin() / eta0.cosh()).asin();
+        let mut lat = chi;
+        for (j, d) in s.delta.iter().enumerate() {
+            lat += d * (2.0 * (j + 1) as f64 * chi).sin();
+        }
+        let lon = eta0.sinh().atan2(xi0.cos());
+        Geodetic::new(
+            lat.to_degrees(),
+            wrap_longitude(lon.to_degrees() + self.central_meridian()),
+            0.0,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_roundtrip(projection: &dyn Projection, g: Geodetic) {
+        let back = projection.unproject(&projection.project(&g));
+        assert!((back.latitude() - g.latitude()).abs() < 1e-9, "{:?}", back);
+        assert!(
+            (back.longitude() - g.longitude()).abs() < 1e-9,
+            "{:?}",
+            back
+        );
+    }
+
+    #[test]
+    fn web_mercator_test() {
+        let p = WebMercator.project(&Geodetic::new(0.0, 180.0, 0.0));
+        assert!((p.x() + 20_037_508.342_789_244).abs() < 1e-6 && p.y().abs() < 1e-9);
+        let corner = WebMercator.project(&Geodetic::new(90.0, 0.0, 0.0));
+        assert!((corner.y() - 20_037_508.342_789_244).abs() < 1e-3);
+        assert_roundtrip(&WebMercator, Geodetic::new(51.4779, -0.0015, 0.0));
+    }
+
+    #[test]
+    fn utm_test() {
+        let cn_tower = Geodetic::new(43.642_567, -79.387_139, 0.0);
+        let utm = Utm::for_geodetic(&cn_tower);
+        assert_eq!((utm.zone(), utm.is_north()), (17, true));
+        let p = utm.project(&cn_tower);
+        assert!((p.x() - 630_084.0).abs() < 1.0 && (p.y() - 4_833_439.0).abs() < 1.0);
+        assert_roundtrip(&utm, cn_tower);
+        let south = Geodetic::new(-33.8688, 151.2093, 0.0);
+        let utm = Utm::for_geodetic(&south);
+        assert_eq!((utm.zone(), utm.is_north()), (56, false));
+        assert_roundtrip(&utm, south);
+        assert_eq!(Utm::for_geodetic(&Geodetic::new(60.0, 5.0, 0.0)).zone(), 32);
+        assert_eq!(
+            Utm::for_geodetic(&Geodetic::new(78.0, 15.0, 0.0)).zone(),
+            33
+        );
+        assert_eq!(Utm::new(61, true), Err(GeometryError::InvalidZone(61)));
+        let origin = Utm::new(31, true)
+            .unwrap()
+            .project(&Geodetic::new(0.0, 3.0, 0.0));
+        assert!((origin.x() - 500_000.0).abs() < 1e-6 && origin.y().abs() < 1e-6);
+    }
+
+    #[test]
+    fn equirectangular_test() {
+        let plate = Equirectangular::default();
+        let p = plate.project(&Geodetic::new(45.0, 90.0, 0.0));
+        let quarter = Ellipsoid::WGS84.semi_major() * std::f64::consts::FRAC_PI_2;
+        assert!((p.x() - quarter).abs() < 1e-6 && (p.y() - quarter / 2.0).abs() < 1e-6);
+        let shifted = Equirectangular::new(40.0).with_central_meridian(-100.0);
+        assert_roundtrip(&shifted, Geodetic::new(38.5, -95.25, 0.0));
+    }
+}
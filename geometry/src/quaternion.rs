@@ -0,0 +1,382 @@
+use std::ops::Mul;
+
+use crate::angle::Angle;
+use crate::dims::Point;
+use crate::matrix::Matrix3;
+use crate::transform::Affine3;
+use crate::vector::Vector3;
+
+/// Quaternion struct represents a rotation as `w + xi + yj + zk`
+///
+/// Rotations are unit quaternions, constructors normalize their result.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quaternion {
+    w: f64,
+    x: f64,
+    y: f64,
+    z: f64,
+}
+
+/// EulerOrder enum list of intrinsic Tait-Bryan axis orders
+///
+/// `Xyz` rotates about x, then the new y, then the newest z, which is the
+/// same as rotating about the fixed z, y and x axes in that order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EulerOrder {
+    Xyz,
+    Xzy,
+    Yxz,
+    Yzx,
+    Zxy,
+    Zyx,
+}
+
+impl EulerOrder {
+    /// Axis indices in application order
+    fn axes(&self) -> [usize; 3] {
+        match self {
+            EulerOrder::Xyz => [0, 1, 2],
+            EulerOrder::Xzy => [0, 2, 1],
+            EulerOrder::Yxz => [1, 0, 2],
+            EulerOrder::Yzx => [1, 2, 0],
+            EulerOrder::Zxy => [2, 0, 1],
+            EulerOrder::Zyx => [2, 1, 0],
+        }
+    }
+}
+
+/// Unit vector along axis `i`
+fn axis(i: usize) -> Vector3 {
+    let mut c = [0.0; 3];
+    c[i] = 1.0;
+    Vector3::from(c)
+}
+
+impl Quaternion {
+    /// Creates a quaternion from its components without normalizing
+    pub fn new(w: f64, x: f64, y: f64, z: f64) -> Self {
+        Quaternion { w, x, y, z }
+    }
+
+    /// The identity rotation
+    pub fn identity() -> Self {
+        Quaternion::new(1.0, 0.0, 0.0, 0.0)
+    }
+
+    /// Scalar part
+    pub fn w(&self) -> f64 {
+        self.w
+    }
+
+    /// Vector part
+    pub fn vector(&self) -> Vector3 {
+        Vector3::new(self.x, self.y, self.z)
+    }
+
+    /// Components as `[w, x, y, z]`
+    pub fn to_array(&self) -> [f64; 4] {
+        [self.w, self.x, self.y, self.z]
+    }
+
+    /// Counter-clockwise rotation about `axis`, identity for a zero axis
+    pub fn from_axis_angle(axis: Vector3, angle: Angle) -> Self {
+        match axis.normalize() {
+            Some(k) => {
+                let (s, c) = (angle / 2.0).sin_cos();
+                Quaternion::new(c, k.x() * s, k.y() * s, k.z() * s)
+            }
+            None => Quaternion::identity(),
+        }
+    }
+
+    /// Unit axis and angle in `[0, pi]`, the x axis is returned for the identity
+    pub fn to_axis_angle(&self) -> (Vector3, Angle) {
+        let q = self.canonical();
+        let v = q.vector();
+        let angle = Angle::atan2(v.length(), q.w) * 2.0;
+        (v.normalize().unwrap_or_else(Vector3::unit_x), angle)
+    }
+
+    /// Rotation from intrinsic Euler angles, applied in `order`
+    pub fn from_euler(order: EulerOrder, angles: [Angle; 3]) -> Self {
+        let [i, j, k] = order.axes();
+        Quaternion::from_axis_angle(axis(i), angles[0])
+            * Quaternion::from_axis_angle(axis(j), angles[1])
+            * Quaternion::from_axis_angle(axis(k), angles[2])
+    }
+
+    /// Intrinsic Euler angles for `order`
+    ///
+    /// The middle angle is in `[-pi/2, pi/2]`; at gimbal lock the last angle is zero.
+    pub fn to_euler(&self, order: EulerOrder) -> [Angle; 3] {
+        let [i, j, k] = order.axes();
+        let r = self.to_matrix();
+        let m = |a: usize, b: usize| r.get(a, b);
+        let s = if (j + 3 - i) % 3 == 1 { 1.0 } else { -1.0 };
+        let sin_b = (s * m(i, k)).clamp(-1.0, 1.0);
+        let b = sin_b.asin();
+        if sin_b.abs() > 1.0 - 1e-12 {
+            let a = Angle::atan2(s * m(k, j), m(j, j));
+            [a, Angle::from_radians(b), Angle::zero()]
+        } else {
+            let a = Angle::atan2(-s * m(j, k), m(k, k));
+            let c = Angle::atan2(-s * m(i, j), m(i, i));
+            [a, Angle::from_radians(b), c]
+        }
+    }
+
+    /// Rotation with the same effect as an orthonormal matrix
+    pub fn from_matrix(m: &Matrix3) -> Self {
+        let r = |a: usize, b: usize| m.get(a, b);
+        let trace = r(0, 0) + r(1, 1) + r(2, 2);
+        let q = if trace > 0.0 {
+            let s = (trace + 1.0).sqrt() * 2.0;
+            Quaternion::new(
+                s / 4.0,
+                (r(2, 1) - r(1, 2)) / s,
+                (r(0, 2) - r(2, 0)) / s,
+                (r(1, 0) - r(0, 1)) / s,
+            )
+        } else if r(0, 0) > r(1, 1) && r(0, 0) > r(2, 2) {
+            let s = (1.0 + r(0, 0) - r(1, 1) - r(2, 2)).sqrt() * 2.0;
+            Quaternion::new(
+                (r(2, 1) - r(1, 2)) / s,
+                s / 4.0,
+                (r(0, 1) + r(1, 0)) / s,
+                (r(0, 2) + r(2, 0)) / s,
+            )
+        } else if r(1, 1) > r(2, 2) {
+            let s = (1.0 + r(1, 1) - r(0, 0) - r(2, 2)).sqrt() * 2.0;
+            Quaternion::new(
+                (r(0, 2) - r(2, 0)) / s,
+                (r(0, 1) + r(1, 0)) / s,
+                s / 4.0,
+                (r(1, 2) + r(2, 1)) / s,
+            )
+        } else {
+            let s = (1.0 + r(2, 2) - r(0, 0) - r(1, 1)).sqrt() * 2.0;
+            Quaternion::new(
+                (r(1, 0) - r(0, 1)) / s,
+                (r(0, 2) + r(2, 0)) / s,
+                (r(1, 2) + r(2, 1)) / s,
+                s / 4.0,
+            )
+        };
+        q.normalize()
+    }
+
+    /// Orthonormal rotation matrix
+    pub fn to_matrix(&self) -> Matrix3 {
+        let Quaternion { w, x, y, z } = self.normalize();
+        Matrix3::new([
+            [
+                1.0 - 2.0 * (y * y + z * z),
+                2.0 * (x * y - w * z),
+                2.0 * (x * z + w * y),
+            ],
+            [
+                2.0 * (x * y + w * z),
+                1.0 - 2.0 * (x * x + z * z),
+                2.0 * (y * z - w * x),
+            ],
+            [
+                2.0 * (x * z - w * y),
+                2.0 * (y * z + w * x),
+                1.0 - 2.0 * (x * x + y * y),
+            ],
+        ])
+    }
+
+    /// Four dimensional dot product
+    pub fn dot(&self, other: &Quaternion) -> f64 {
+        self.w * other.w + self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// Euclidean norm
+    pub fn norm(&self) -> f64 {
+        self.dot(self).sqrt()
+    }
+
+    /// Unit quaternion in the same direction, identity for zero
+    pub fn normalize(&self) -> Quaternion {
+        let n = self.norm();
+        if n > 0.0 && n.is_finite() {
+            Quaternion::new(self.w / n, self.x / n, self.y / n, self.z / n)
+        } else {
+            Quaternion::identity()
+        }
+    }
+
+    /// Conjugate, the inverse rotation for unit quaternions
+    pub fn conjugate(&self) -> Quaternion {
+        Quaternion::new(self.w, -self.x, -self.y, -self.z)
+    }
+
+    /// Multiplicative inverse, `None` for zero
+    pub fn inverse(&self) -> Option<Quaternion> {
+        let n2 = self.dot(self);
+        if n2 > 0.0 && n2.is_finite() {
+            let c = self.conjugate();
+            Some(Quaternion::new(c.w / n2, c.x / n2, c.y / n2, c.z / n2))
+        } else {
+            None
+        }
+    }
+
+    /// Representative with non-negative scalar part, `q` and `-q` are the same rotation
+    fn canonical(&self) -> Quaternion {
+        if self.w < 0.0 {
+            Quaternion::new(-self.w, -self.x, -self.y, -self.z)
+        } else {
+            *self
+        }
+    }
+
+    /// Spherical linear interpolation along the shortest arc
+    pub fn slerp(&self, other: &Quaternion, t: f64) -> Quaternion {
+        let a = self.normalize();
+        let mut b = other.normalize();
+        let mut cos = a.dot(&b);
+        if cos < 0.0 {
+            b = Quaternion::new(-b.w, -b.x, -b.y, -b.z);
+            cos = -cos;
+        }
+        let (wa, wb) = if cos > 1.0 - 1e-9 {
+            (1.0 - t, t)
+        } else {
+            let theta = cos.acos();
+            let sin = theta.sin();
+            (((1.0 - t) * theta).sin() / sin, (t * theta).sin() / sin)
+        };
+        Quaternion::new(
+            wa * a.w + wb * b.w,
+            wa * a.x + wb * b.x,
+            wa * a.y + wb * b.y,
+            wa * a.z + wb * b.z,
+        )
+        .normalize()
+    }
+
+    /// Rotates a vector
+    pub fn rotate_vector(&self, v: &Vector3) -> Vector3 {
+        let q = self.normalize();
+        let u = q.vector();
+        let t = u.cross(v) * 2.0;
+        *v + t * q.w + u.cross(&t)
+    }
+
+    /// Rotates a point about the origin
+    pub fn rotate_point(&self, p: &Point) -> Point {
+        Point::from(self.rotate_vector(&p.to_vector()))
+    }
+}
+
+impl Default for Quaternion {
+    fn default() -> Self {
+        Quaternion::identity()
+    }
+}
+
+impl Mul for Quaternion {
+    type Output = Quaternion;
+
+    /// Hamilton product, `(a * b)` rotates by `b` first
+    fn mul(self, r: Quaternion) -> Quaternion {
+        Quaternion::new(
+            self.w * r.w - self.x * r.x - self.y * r.y - self.z * r.z,
+            self.w * r.x + self.x * r.w + self.y * r.z - self.z * r.y,
+            self.w * r.y - self.x * r.z + self.y * r.w + self.z * r.x,
+            self.w * r.z + self.x * r.y - self.y * r.x + self.z * r.w,
+        )
+    }
+}
+
+impl Mul<Point> for Quaternion {
+    type Output = Point;
+
+    fn mul(self, p: Point) -> Point {
+        self.rotate_point(&p)
+    }
+}
+
+impl Mul<Vector3> for Quaternion {
+    type Output = Vector3;
+
+    fn mul(self, v: Vector3) -> Vector3 {
+        self.rotate_vector(&v)
+    }
+}
+
+impl From<Quaternion> for Matrix3 {
+    fn from(q: Quaternion) -> Matrix3 {
+        q.to_matrix()
+    }
+}
+
+impl From<Quaternion> for Affine3 {
+    fn from(q: Quaternion) -> Affine3 {
+        Affine3::new(q.to_matrix(), Vector3::zero())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    const ORDERS: [EulerOrder; 6] = [
+        EulerOrder::Xyz,
+        EulerOrder::Xzy,
+        EulerOrder::Yxz,
+        EulerOrder::Yzx,
+        EulerOrder::Zxy,
+        EulerOrder::Zyx,
+    ];
+
+    fn same_rotation(a: &Quaternion, b: &Quaternion) -> bool {
+        (a.normalize().dot(&b.normalize()).abs() - 1.0).abs() < 1e-12
+    }
+
+    #[test]
+    fn rotate_and_compose_test() {
+        let q = Quaternion::from_axis_angle(Vector3::unit_z(), Angle::from_radians(FRAC_PI_2));
+        let p = q * Point::new(1.0, 0.0, 0.0);
+        assert!(p.distance(&Point::new(0.0, 1.0, 0.0)) < 1e-12);
+        let half = Quaternion::identity().slerp(&(q * q), 0.5);
+        assert!(same_rotation(&half, &q));
+        let (axis, angle) = (q * q).to_axis_angle();
+        assert!((angle.radians() - PI).abs() < 1e-12);
+        assert!((axis - Vector3::unit_z()).length() < 1e-12);
+        assert!(same_rotation(
+            &(q * q.inverse().unwrap()),
+            &Quaternion::identity()
+        ));
+    }
+
+    #[test]
+    fn matrix_roundtrip_test() {
+        let q = Quaternion::new(0.3, -0.5, 0.7, 0.2).normalize();
+        assert!(same_rotation(&Quaternion::from_matrix(&q.to_matrix()), &q));
+        let v = Vector3::new(1.0, -2.0, 0.5);
+        assert!((q.to_matrix() * v - q * v).length() < 1e-12);
+        let flip = Quaternion::from_axis_angle(Vector3::new(1.0, 1.0, 0.0), Angle::half_turn());
+        assert!(same_rotation(
+            &Quaternion::from_matrix(&flip.to_matrix()),
+            &flip
+        ));
+    }
+
+    #[test]
+    fn euler_roundtrip_test() {
+        for order in ORDERS.iter() {
+            for angles in [[0.3, -1.1, 2.5], [-2.0, 0.4, -0.7], [1.0, FRAC_PI_2, 0.5]].iter() {
+                let q = Quaternion::from_euler(*order, angles.map(Angle::from_radians));
+                let back = Quaternion::from_euler(*order, q.to_euler(*order));
+                assert!(same_rotation(&q, &back), "{:?} {:?}", order, angles);
+            }
+            let q = Quaternion::from_euler(*order, [0.3, -1.1, 2.5].map(Angle::from_radians));
+            let e = q.to_euler(*order).map(|a| a.radians());
+            assert!((e[0] - 0.3).abs() < 1e-12 && (e[2] - 2.5).abs() < 1e-12);
+        }
+    }
+}
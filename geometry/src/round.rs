@@ -0,0 +1,656 @@
+//! Round primitives: circles and ellipses in the plane, spheres, capsules and cylinders in space
+//!
+//! Every shape is solid, so containment includes the interior and the closest
+//! point to an interior point is the point itself.
+
+use std::f64::consts::PI;
+
+use crate::angle::Angle;
+use crate::dimension::{D2, D3};
+use crate::dims::{Dimensional, Point};
+use crate::error::GeometryError;
+use crate::linear::Segment;
+use crate::pointn::Point2;
+use crate::transform::{Affine3, TryTransformable};
+use crate::vector::Vector3;
+
+/// Upper bound on the segments returned by [`chord_segments`]
+const MAX_SEGMENTS: usize = 1 << 16;
+
+/// Segments needed to approximate a full circle of `radius` within `tolerance`
+///
+/// The tolerance bounds the distance between each chord and its arc. At
+/// least three and at most 65536 segments are returned. The cap applies to
+/// one ring only, callers stacking many rings must bound the total themselves.
+pub fn chord_segments(radius: f64, tolerance: f64) -> usize {
+    let ratio = (tolerance / radius).min(1.0);
+    if ratio.is_nan() || ratio <= 0.0 {
+        return MAX_SEGMENTS;
+    }
+    let n = (PI / (1.0 - ratio).acos()).ceil();
+    (n as usize).clamp(3, MAX_SEGMENTS)
+}
+
+/// Fails unless `radius` is finite and positive
+fn check_radius(radius: f64) -> Result<(), GeometryError> {
+    if !radius.is_finite() {
+        Err(GeometryError::NonFinite)
+    } else if radius <= 0.0 {
+        Err(GeometryError::Degenerate)
+    } else {
+        Ok(())
+    }
+}
+
+fn check_finite(coords: &[f64]) -> Result<(), GeometryError> {
+    if coords.iter().all(|v| v.is_finite()) {
+        Ok(())
+    } else {
+        Err(GeometryError::NonFinite)
+    }
+}
+
+/// Stretch of a map that keeps round shapes round
+fn similarity_scale(t: &Affine3) -> Result<f64, GeometryError> {
+    t.uniform_scale().ok_or(GeometryError::UnsupportedTransform)
+}
+
+/// Moves an outside point onto a ball of `radius` around `center`
+fn clamp_to_ball(center: &Point, radius: f64, p: &Point) -> Point {
+    let offset = *p - *center;
+    let length = offset.length();
+    if length <= radius {
+        *p
+    } else {
+        *center + offset * (radius / length)
+    }
+}
+
+/// Circle struct represents a disk in the plane
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Circle {
+    center: Point2,
+    radius: f64,
+}
+
+impl Circle {
+    /// Disk of `radius` around `center`, failing unless the radius is positive
+    pub fn new(center: Point2, radius: f64) -> Result<Self, GeometryError> {
+        check_finite(center.coords())?;
+        check_radius(radius)?;
+        Ok(Circle { center, radius })
+    }
+
+    /// Center of the circle
+    pub fn center(&self) -> Point2 {
+        self.center
+    }
+
+    /// Radius of the circle
+    pub fn radius(&self) -> f64 {
+        self.radius
+    }
+
+    /// Enclosed area
+    pub fn area(&self) -> f64 {
+        PI * self.radius * self.radius
+    }
+
+    /// Length of the boundary
+    pub fn perimeter(&self) -> f64 {
+        2.0 * PI * self.radius
+    }
+
+    /// Point on the boundary at `angle` from the positive x axis
+    pub fn point_at(&self, angle: Angle) -> Point2 {
+        let (s, c) = angle.sin_cos();
+        Point2::new(
+            self.center.x() + self.radius * c,
+            self.center.y() + self.radius * s,
+        )
+    }
+
+    /// True when `p` lies inside or on the boundary
+    pub fn contains(&self, p: &Point2) -> bool {
+        self.center.distance_squared(p) <= self.radius * self.radius
+    }
+
+    /// Point of the disk closest to `p`
+    pub fn closest_point(&self, p: &Point2) -> Point2 {
+        let d = self.center.distance(p);
+        if d <= self.radius {
+            *p
+        } else {
+            self.center.lerp(p, self.radius / d)
+        }
+    }
+}
+
+impl Dimensional for Circle {
+    type Dim = D2;
+
+    fn measure(&self) -> f64 {
+        self.area()
+    }
+
+    fn bounding_box(&self) -> (Point2, Point2) {
+        let (x, y, r) = (self.center.x(), self.center.y(), self.radius);
+        (Point2::new(x - r, y - r), Point2::new(x + r, y + r))
+    }
+
+    fn centroid(&self) -> Point2 {
+        self.center
+    }
+}
+
+/// Ellipse struct represents a filled ellipse in the plane
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ellipse {
+    center: Point2,
+    semi_axes: [f64; 2],
+    rotation: Angle,
+}
+
+impl Ellipse {
+    /// Ellipse with semi-axes along x and y before rotating counter-clockwise by `rotation`
+    pub fn new(
+        center: Point2,
+        semi_x: f64,
+        semi_y: f64,
+        rotation: Angle,
+    ) -> Result<Self, GeometryError> {
+        check_finite(center.coords())?;
+        check_finite(&[rotation.radians()])?;
+        check_radius(semi_x)?;
+        check_radius(semi_y)?;
+        Ok(Ellipse {
+            center,
+            semi_axes: [semi_x, semi_y],
+            rotation,
+        })
+    }
+
+    /// Center of the ellipse
+    pub fn center(&self) -> Point2 {
+        self.center
+    }
+
+    /// Semi-axis lengths along the rotated x and y axes
+    pub fn semi_axes(&self) -> [f64; 2] {
+        self.semi_axes
+    }
+
+    /// Counter-clockwise rotation of the first semi-axis from the x axis
+    pub fn rotation(&self) -> Angle {
+        self.rotation
+    }
+
+    /// Enclosed area
+    pub fn area(&self) -> f64 {
+        PI * self.semi_axes[0] * self.semi_axes[1]
+    }
+
+    /// Length of the boundary by the arithmetic-geometric mean
+    ///
+    /// The iteration converges quadratically, so the result is accurate to a
+    /// few ulps for any elongation.
+    pub fn perimeter(&self) -> f64 {
+        let [a, b] = self.semi_axes;
+        let (mut x, mut y) = (a.max(b), a.min(b));
+        let mut weight = 0.5;
+        let mut sum = weight * (x - y) * (x + y);
+        for _ in 0..64 {
+            if x - y <= f64::EPSILON * x {
+                break;
+            }
+            let c = (x - y) / 2.0;
+            let next = ((x + y) / 2.0, (x * y).sqrt());
+            x = next.0;
+            y = next.1;
+            weight *= 2.0;
+            sum += weight * c * c;
+        }
+        2.0 * PI * (a.max(b).powi(2) - sum) / x
+    }
+
+    /// Boundary point at parametric angle `t`, `(a cos t, b sin t)` before rotation
+    pub fn point_at(&self, t: Angle) -> Point2 {
+        let (s, c) = t.sin_cos();
+        self.world_of(self.semi_axes[0] * c, self.semi_axes[1] * s)
+    }
+
+    fn local_of(&self, p: &Point2) -> [f64; 2] {
+        let (s, c) = self.rotation.sin_cos();
+        let (dx, dy) = (p.x() - self.center.x(), p.y() - self.center.y());
+        [c * dx + s * dy, -s * dx + c * dy]
+    }
+
+    fn world_of(&self, x: f64, y: f64) -> Point2 {
+        let (s, c) = self.rotation.sin_cos();
+        Point2::new(
+            self.center.x() + c * x - s * y,
+            self.center.y() + s * x + c * y,
+        )
+    }
+
+    /// True when `p` lies inside or on the boundary
+    pub fn contains(&self, p: &Point2) -> bool {
+        let [x, y] = self.local_of(p);
+        let [a, b] = self.semi_axes;
+        (x / a).powi(2) + (y / b).powi(2) <= 1.0
+    }
+
+    /// Point of the ellipse closest to `p`
+    ///
+    /// Outside points are projected onto the boundary by bisection on the
+    /// Lagrange multiplier, which converges for every position.
+    pub fn closest_point(&self, p: &Point2) -> Point2 {
+        if self.contains(p) {
+            return *p;
+        }
+        let y = self.local_of(p);
+        let [a, b] = self.semi_axes;
+        let e = [a * a, b * b];
+        let f = |t: f64| (a * y[0] / (t + e[0])).powi(2) + (b * y[1] / (t + e[1])).powi(2) - 1.0;
+        let (mut lo, mut hi) = (0.0, (e[0] * y[0] * y[0] + e[1] * y[1] * y[1]).sqrt());
+        for _ in 0..200 {
+            let mid = (lo + hi) / 2.0;
+            if mid <= lo || mid >= hi {
+                break;
+            }
+            if f(mid) > 0.0 {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        let t = (lo + hi) / 2.0;
+        self.world_of(e[0] * y[0] / (t + e[0]), e[1] * y[1] / (t + e[1]))
+    }
+}
+
+impl Dimensional for Ellipse {
+    type Dim = D2;
+
+    fn measure(&self) -> f64 {
+        self.area()
+    }
+
+    fn bounding_box(&self) -> (Point2, Point2) {
+        let (s, c) = self.rotation.sin_cos();
+        let [a, b] = self.semi_axes;
+        let w = (a * a * c * c + b * b * s * s).sqrt();
+        let h = (a * a * s * s + b * b * c * c).sqrt();
+        let (x, y) = (self.center.x(), self.center.y());
+        (Point2::new(x - w, y - h), Point2::new(x + w, y + h))
+    }
+
+    fn centroid(&self) -> Point2 {
+        self.center
+    }
+}
+
+/// Sphere struct represents a solid ball in space
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Sphere {
+    center: Point,
+    radius: f64,
+}
+
+impl Sphere {
+    /// Ball of `radius` around `center`, failing unless the radius is positive
+    pub fn new(center: Point, radius: f64) -> Result<Self, GeometryError> {
+        check_finite(&center.to_array())?;
+        check_radius(radius)?;
+        Ok(Sphere { center, radius })
+    }
+
+    /// Center of the sphere
+    pub fn center(&self) -> Point {
+        self.center
+    }
+
+    /// Radius of the sphere
+    pub fn radius(&self) -> f64 {
+        self.radius
+    }
+
+    /// Enclosed volume
+    pub fn volume(&self) -> f64 {
+        4.0 / 3.0 * PI * self.radius.powi(3)
+    }
+
+    /// Area of the boundary
+    pub fn surface_area(&self) -> f64 {
+        4.0 * PI * self.radius * self.radius
+    }
+
+    /// True when `p` lies inside or on the boundary
+    pub fn contains(&self, p: &Point) -> bool {
+        self.center.distance_squared(p) <= self.radius * self.radius
+    }
+
+    /// Point of the ball closest to `p`
+    pub fn closest_point(&self, p: &Point) -> Point {
+        clamp_to_ball(&self.center, self.radius, p)
+    }
+}
+
+impl Dimensional for Sphere {
+    type Dim = D3;
+
+    fn measure(&self) -> f64 {
+        self.volume()
+    }
+
+    fn bounding_box(&self) -> (Point, Point) {
+        let r = Vector3::new(self.radius, self.radius, self.radius);
+        (self.center - r, self.center + r)
+    }
+
+    fn centroid(&self) -> Point {
+        self.center
+    }
+}
+
+impl TryTransformable for Sphere {
+    /// Fails unless `t` scales every direction equally
+    fn try_transform(&self, t: &Affine3) -> Result<Self, GeometryError> {
+        let scale = similarity_scale(t)?;
+        Sphere::new(t.apply_point(&self.center), self.radius * scale)
+    }
+}
+
+/// Capsule struct represents the points within a radius of a segment
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Capsule {
+    axis: Segment,
+    radius: f64,
+}
+
+impl Capsule {
+    /// Capsule around the segment from `start` to `end`, a zero length gives a sphere
+    pub fn new(start: Point, end: Point, radius: f64) -> Result<Self, GeometryError> {
+        let axis = Segment::new(start, end)?;
+        check_radius(radius)?;
+        Ok(Capsule { axis, radius })
+    }
+
+    /// Segment joining the centers of the two hemispherical caps
+    pub fn axis(&self) -> Segment {
+        self.axis
+    }
+
+    /// Radius of the capsule
+    pub fn radius(&self) -> f64 {
+        self.radius
+    }
+
+    /// Enclosed volume
+    pub fn volume(&self) -> f64 {
+        let r = self.radius;
+        PI * r * r * (self.axis.length() + 4.0 / 3.0 * r)
+    }
+
+    /// Area of the boundary
+    pub fn surface_area(&self) -> f64 {
+        2.0 * PI * self.radius * (self.axis.length() + 2.0 * self.radius)
+    }
+
+    /// True when `p` lies inside or on the boundary
+    pub fn contains(&self, p: &Point) -> bool {
+        self.axis.distance(p) <= self.radius
+    }
+
+    /// Point of the capsule closest to `p`
+    pub fn closest_point(&self, p: &Point) -> Point {
+        clamp_to_ball(&self.axis.closest_point(p), self.radius, p)
+    }
+}
+
+impl Dimensional for Capsule {
+    type Dim = D3;
+
+    fn measure(&self) -> f64 {
+        self.volume()
+    }
+
+    fn bounding_box(&self) -> (Point, Point) {
+        let r = Vector3::new(self.radius, self.radius, self.radius);
+        let (a, b) = (self.axis.start(), self.axis.end());
+        (a.min(&b) - r, a.max(&b) + r)
+    }
+
+    fn centroid(&self) -> Point {
+        self.axis.midpoint()
+    }
+}
+
+impl TryTransformable for Capsule {
+    /// Fails unless `t` scales every direction equally
+    fn try_transform(&self, t: &Affine3) -> Result<Self, GeometryError> {
+        let scale = similarity_scale(t)?;
+        Capsule::new(
+            t.apply_point(&self.axis.start()),
+            t.apply_point(&self.axis.end()),
+            self.radius * scale,
+        )
+    }
+}
+
+/// Cylinder struct represents a solid right circular cylinder with flat caps
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Cylinder {
+    axis: Segment,
+    radius: f64,
+}
+
+impl Cylinder {
+    /// Cylinder whose caps are centered on `base` and `top`, failing when they coincide
+    pub fn new(base: Point, top: Point, radius: f64) -> Result<Self, GeometryError> {
+        let axis = Segment::new(base, top)?;
+        check_radius(radius)?;
+        if base == top {
+            return Err(GeometryError::Degenerate);
+        }
+        Ok(Cylinder { axis, radius })
+    }
+
+    /// Segment joining the centers of the caps
+    pub fn axis(&self) -> Segment {
+        self.axis
+    }
+
+    /// Radius of the cylinder
+    pub fn radius(&self) -> f64 {
+        self.radius
+    }
+
+    /// Distance between the caps
+    pub fn height(&self) -> f64 {
+        self.axis.length()
+    }
+
+    /// Enclosed volume
+    pub fn volume(&self) -> f64 {
+        PI * self.radius * self.radius * self.height()
+    }
+
+    /// Area of the boundary including both caps
+    pub fn surface_area(&self) -> f64 {
+        2.0 * PI * self.radius * (self.height() + self.radius)
+    }
+
+    /// True when `p` lies inside or on the boundary
+    pub fn contains(&self, p: &Point) -> bool {
+        let t = self.axis.parameter_of(p);
+        (0.0..=1.0).contains(&t) && self.axis.point_at(t).distance(p) <= self.radius
+    }
+
+    /// Point of the cylinder closest to `p`
+    pub fn closest_point(&self, p: &Point) -> Point {
+        let t = self.axis.parameter_of(p).clamp(0.0, 1.0);
+        let on_axis = self.axis.point_at(t);
+        let radial = self.axis.project(p);
+        let offset = *p - radial;
+        let length = offset.length();
+        let scale = if length > self.radius {
+            self.radius / length
+        } else {
+            1.0
+        };
+        on_axis + offset * scale
+    }
+}
+
+impl Dimensional for Cylinder {
+    type Dim = D3;
+
+    fn measure(&self) -> f64 {
+        self.volume()
+    }
+
+    /// Tight box around both cap disks
+    fn bounding_box(&self) -> (Point, Point) {
+        let (a, b) = (self.axis.start(), self.axis.end());
+        let d = (self.axis.direction() / self.height()).to_array();
+        let r = Vector3::from(d.map(|c| self.radius * (1.0 - c * c).max(0.0).sqrt()));
+        (a.min(&b) - r, a.max(&b) + r)
+    }
+
+    fn centroid(&self) -> Point {
+        self.axis.midpoint()
+    }
+}
+
+impl TryTransformable for Cylinder {
+    /// Fails unless `t` scales every direction equally
+    fn try_transform(&self, t: &Affine3) -> Result<Self, GeometryError> {
+        let scale = similarity_scale(t)?;
+        Cylinder::new(
+            t.apply_point(&self.axis.start()),
+            t.apply_point(&self.axis.end()),
+            self.radius * scale,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::approx::ApproxEq;
+
+    #[test]
+    fn chord_segments_test() {
+        assert_eq!(chord_segments(1.0, 2.0), 3);
+        assert_eq!(chord_segments(1.0, 1.0 - (PI / 8.0).cos()), 8);
+        assert_eq!(chord_segments(1.0, 0.0), 65536);
+    }
+
+    #[test]
+    fn planar_test() {
+        let c = Circle::new(Point2::new(1.0, 1.0), 2.0).unwrap();
+        assert!((c.area() - 4.0 * PI).abs() < 1e-12);
+        assert!(c.contains(&Point2::new(2.0, 2.0)));
+        assert_eq!(
+            c.closest_point(&Point2::new(1.0, 5.0)),
+            Point2::new(1.0, 3.0)
+        );
+        assert_eq!(
+            Circle::new(Point2::origin(), 0.0),
+            Err(GeometryError::Degenerate)
+        );
+        let e = Ellipse::new(Point2::origin(), 3.0, 1.0, Angle::from_degrees(90.0)).unwrap();
+        assert!(e.contains(&Point2::new(0.0, 2.9)) && !e.contains(&Point2::new(2.0, 0.0)));
+        assert!(e
+            .closest_point(&Point2::new(0.0, 7.0))
+            .abs_diff_eq(&Point2::new(0.0, 3.0), 1e-9));
+        let q = e.closest_point(&Point2::new(4.0, 4.0));
+        assert!(e
+            .local_of(&q)
+            .abs_diff_eq(&e.local_of(&e.closest_point(&q)), 1e-9));
+        let (lo, hi) = e.bounding_box();
+        assert!(lo.abs_diff_eq(&Point2::new(-1.0, -3.0), 1e-12));
+        assert!(hi.abs_diff_eq(&Point2::new(1.0, 3.0), 1e-12));
+        let circle = Ellipse::new(Point2::origin(), 1.0, 1.0, Angle::zero()).unwrap();
+        assert!((circle.perimeter() - 2.0 * PI).abs() < 1e-12);
+        // Reference lengths from the trapezoid rule on the arc length integrand
+        for (a, b, length) in [
+            (3.0, 1.0, 13.364893220555606),
+            (1.0, 10.0, 40.63974180100898),
+            (100.0, 1.0, 400.1098329722748),
+        ] {
+            let e = Ellipse::new(Point2::origin(), a, b, Angle::zero()).unwrap();
+            assert!((e.perimeter() - length).abs() < 1e-12 * length);
+        }
+    }
+
+    #[test]
+    fn solid_test() {
+        let s = Sphere::new(Point::origin(), 2.0).unwrap();
+        assert!((s.volume() - 32.0 / 3.0 * PI).abs() < 1e-12);
+        assert_eq!(
+            s.closest_point(&Point::new(0.0, 0.0, 4.0)),
+            Point::new(0.0, 0.0, 2.0)
+        );
+        let cap = Capsule::new(Point::origin(), Point::new(0.0, 0.0, 4.0), 1.0).unwrap();
+        assert!(cap.contains(&Point::new(0.0, 0.5, 4.5)));
+        assert!(!cap.contains(&Point::new(0.0, 1.0, 4.5)));
+        assert_eq!(
+            cap.closest_point(&Point::new(3.0, 0.0, 2.0)),
+            Point::new(1.0, 0.0, 2.0)
+        );
+        assert_eq!(cap.bounding_box().1, Point::new(1.0, 1.0, 5.0));
+        let cyl = Cylinder::new(Point::origin(), Point::new(0.0, 0.0, 4.0), 1.0).unwrap();
+        assert!(!cyl.contains(&Point::new(0.0, 0.5, 4.5)));
+        assert_eq!(
+            cyl.closest_point(&Point::new(3.0, 0.0, 6.0)),
+            Point::new(1.0, 0.0, 4.0)
+        );
+        assert_eq!(
+            cyl.closest_point(&Point::new(0.5, 0.0, 1.0)),
+            Point::new(0.5, 0.0, 1.0)
+        );
+        assert_eq!(
+            cyl.bounding_box(),
+            (Point::new(-1.0, -1.0, 0.0), Point::new(1.0, 1.0, 4.0))
+        );
+        assert!((cyl.measure() - 4.0 * PI).abs() < 1e-12);
+        assert_eq!(
+            Cylinder::new(Point::origin(), Point::origin(), 1.0),
+            Err(GeometryError::Degenerate)
+        );
+    }
+
+    #[test]
+    fn transform_test() {
+        let s = Sphere::new(Point::new(1.0, 0.0, 0.0), 1.0).unwrap();
+        let moved = s.try_scaled(2.0).unwrap();
+        assert_eq!(
+            (moved.center(), moved.radius()),
+            (Point::new(2.0, 0.0, 0.0), 2.0)
+        );
+        let squash = Affine3::scaling(1.0, 1.0, 0.5);
+        assert_eq!(
+            s.try_transform(&squash),
+            Err(GeometryError::UnsupportedTransform)
+        );
+        assert_eq!(s.try_scaled(0.0), Err(GeometryError::Degenerate));
+        let cap = Capsule::new(Point::origin(), Point::new(0.0, 0.0, 2.0), 0.5).unwrap();
+        let tipped = cap
+            .try_rotated(Vector3::unit_x(), Angle::from_degrees(90.0))
+            .unwrap();
+        assert!(tipped
+            .axis()
+            .end()
+            .abs_diff_eq(&Point::new(0.0, -2.0, 0.0), 1e-12));
+        assert!((tipped.volume() - cap.volume()).abs() < 1e-12);
+        let cyl = Cylinder::new(Point::origin(), Point::new(0.0, 0.0, 1.0), 1.0).unwrap();
+        let shifted = cyl.try_translated(Vector3::unit_x()).unwrap();
+        assert_eq!(shifted.axis().start(), Point::new(1.0, 0.0, 0.0));
+        assert_eq!(
+            cyl.try_transform(&squash),
+            Err(GeometryError::UnsupportedTransform)
+        );
+    }
+}
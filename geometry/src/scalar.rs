@@ -0,0 +1,235 @@
+use std::fmt::Debug;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::dims::Point;
+use crate::predicates;
+
+/// Scalar trait supplies the ring operations coordinates need
+///
+/// Implementors only need to be `Clone` so arbitrary precision types can be used.
+pub trait Scalar:
+    Clone
+    + Debug
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Neg<Output = Self>
+{
+    /// Additive identity
+    fn zero() -> Self;
+
+    /// Multiplicative identity
+    fn one() -> Self;
+
+    /// Converts a small integer constant
+    fn from_i32(v: i32) -> Self;
+
+    /// Nearest `f64` value, used when leaving the scalar type
+    fn to_f64(&self) -> f64;
+
+    /// Value of an `f64`, exact when the type can hold it and nearest otherwise
+    ///
+    /// `None` when `v` is not finite or lies outside the range of the type.
+    fn try_from_f64(v: f64) -> Option<Self>;
+
+    /// True for the additive identity
+    fn is_zero(&self) -> bool {
+        *self == Self::zero()
+    }
+
+    /// False for NaN and infinite values
+    fn is_finite(&self) -> bool {
+        true
+    }
+
+    /// True when squared distances and cross products of coordinate
+    /// differences up to this magnitude cannot overflow
+    ///
+    /// Always true except for integer scalars, which accept magnitudes up to
+    /// `sqrt(MAX / 24)`, enough for a sum of two squared side lengths.
+    fn fits_products(&self) -> bool {
+        true
+    }
+
+    /// True when three points lie exactly on one line
+    ///
+    /// The default evaluates the cross product in `Self`, which is exact for
+    /// rational scalars; floats use adaptive exact predicates and integers
+    /// widen to `i128` so the products cannot overflow.
+    fn collinear(a: &Point<Self>, b: &Point<Self>, c: &Point<Self>) -> bool {
+        let n = (b.clone() - a.clone()).cross(&(c.clone() - a.clone()));
+        n.to_array().iter().all(Scalar::is_zero)
+    }
+
+    /// Absolute value
+    fn abs(&self) -> Self {
+        if *self < Self::zero() {
+            -self.clone()
+        } else {
+            self.clone()
+        }
+    }
+
+    /// Smaller of two values, `self` when they are unordered
+    fn min_of(self, other: Self) -> Self {
+        if other < self {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Larger of two values, `self` when they are unordered
+    fn max_of(self, other: Self) -> Self {
+        if other > self {
+            other
+        } else {
+            self
+        }
+    }
+}
+
+/// Field trait marks scalars with exact or rounded division
+pub trait Field: Scalar + Div<Output = Self> {}
+
+/// Real trait supplies square roots and trigonometry for metric computations
+pub trait Real: Field {
+    /// Nearest value to an `f64`
+    fn from_f64(v: f64) -> Self;
+
+    /// The constant pi
+    fn pi() -> Self;
+
+    fn sqrt(&self) -> Self;
+    fn sin(&self) -> Self;
+    fn cos(&self) -> Self;
+    fn tan(&self) -> Self;
+    fn asin(&self) -> Self;
+    fn acos(&self) -> Self;
+    fn atan2(&self, x: &Self) -> Self;
+}
+
+macro_rules! impl_float {
+    ($t:ident) => {
+        #[allow(clippy::unnecessary_cast)]
+        impl Scalar for $t {
+            fn zero() -> Self {
+                0.0
+            }
+
+            fn one() -> Self {
+                1.0
+            }
+
+            fn from_i32(v: i32) -> Self {
+                v as $t
+            }
+
+            fn to_f64(&self) -> f64 {
+                *self as f64
+            }
+
+            fn try_from_f64(v: f64) -> Option<Self> {
+                Some(v as $t).filter(|v| v.is_finite())
+            }
+
+            fn abs(&self) -> Self {
+                $t::abs(*self)
+            }
+
+            fn is_finite(&self) -> bool {
+                $t::is_finite(*self)
+            }
+
+            fn collinear(a: &Point<Self>, b: &Point<Self>, c: &Point<Self>) -> bool {
+                predicates::collinear3d(&a.to_f64(), &b.to_f64(), &c.to_f64())
+            }
+        }
+
+        impl Field for $t {}
+
+        #[allow(clippy::unnecessary_cast)]
+        impl Real for $t {
+            fn from_f64(v: f64) -> Self {
+                v as $t
+            }
+
+            fn pi() -> Self {
+                std::$t::consts::PI
+            }
+
+            fn sqrt(&self) -> Self {
+                $t::sqrt(*self)
+            }
+
+            fn sin(&self) -> Self {
+                $t::sin(*self)
+            }
+
+            fn cos(&self) -> Self {
+                $t::cos(*self)
+            }
+
+            fn tan(&self) -> Self {
+                $t::tan(*self)
+            }
+
+            fn asin(&self) -> Self {
+                $t::asin(*self)
+            }
+
+            fn acos(&self) -> Self {
+                $t::acos(*self)
+            }
+
+            fn atan2(&self, x: &Self) -> Self {
+                $t::atan2(*self, *x)
+            }
+        }
+    };
+}
+
+macro_rules! impl_int {
+    ($t:ident) => {
+        #[allow(clippy::unnecessary_cast)]
+        impl Scalar for $t {
+            fn zero() -> Self {
+                0
+            }
+
+            fn one() -> Self {
+                1
+            }
+
+            fn from_i32(v: i32) -> Self {
+                v as $t
+            }
+
+            fn to_f64(&self) -> f64 {
+                *self as f64
+            }
+
+            fn try_from_f64(v: f64) -> Option<Self> {
+                let bound = -($t::MIN as f64);
+                let r = v.round();
+                (-bound..bound).contains(&r).then(|| r as $t)
+            }
+
+            fn fits_products(&self) -> bool {
+                let bound = (($t::MAX as f64) / 24.0).sqrt() as $t;
+                (-bound..=bound).contains(self)
+            }
+
+            fn collinear(a: &Point<Self>, b: &Point<Self>, c: &Point<Self>) -> bool {
+                let wide = |p: &Point<Self>| p.to_array().map(i64::from);
+                predicates::collinear_int(wide(a), wide(b), wide(c))
+            }
+        }
+    };
+}
+
+impl_float!(f32);
+impl_float!(f64);
+impl_int!(i32);
+impl_int!(i64);
@@ -0,0 +1,326 @@
+use std::convert::TryFrom;
+use std::ops::Mul;
+
+use crate::angle::Angle;
+use crate::dims::Point;
+use crate::error::GeometryError;
+use crate::matrix::{Matrix3, Matrix4};
+use crate::pointn::Point3;
+use crate::vector::Vector3;
+
+/// Affine3 struct represents an affine map `p -> linear * p + translation`
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Affine3 {
+    linear: Matrix3,
+    translation: Vector3,
+}
+
+impl Affine3 {
+    /// Creates an affine map from its linear part and translation
+    pub fn new(linear: Matrix3, translation: Vector3) -> Self {
+        Affine3 {
+            linear,
+            translation,
+        }
+    }
+
+    /// The identity map
+    pub fn identity() -> Self {
+        Affine3::new(Matrix3::identity(), Vector3::zero())
+    }
+
+    /// Translation by `v`
+    pub fn translation(v: Vector3) -> Self {
+        Affine3::new(Matrix3::identity(), v)
+    }
+
+    /// Scaling about the origin along each axis
+    pub fn scaling(x: f64, y: f64, z: f64) -> Self {
+        Affine3::new(Matrix3::diagonal(x, y, z), Vector3::zero())
+    }
+
+    /// Uniform scaling about the origin
+    pub fn uniform_scaling(s: f64) -> Self {
+        Affine3::scaling(s, s, s)
+    }
+
+    /// Counter-clockwise rotation about the x axis
+    pub fn rotation_x(angle: Angle) -> Self {
+        Affine3::rotation(Vector3::unit_x(), angle)
+    }
+
+    /// Counter-clockwise rotation about the y axis
+    pub fn rotation_y(angle: Angle) -> Self {
+        Affine3::rotation(Vector3::unit_y(), angle)
+    }
+
+    /// Counter-clockwise rotation about the z axis
+    pub fn rotation_z(angle: Angle) -> Self {
+        Affine3::rotation(Vector3::unit_z(), angle)
+    }
+
+    /// Counter-clockwise rotation about `axis` through the origin
+    ///
+    /// A zero axis gives the identity.
+    pub fn rotation(axis: Vector3, angle: Angle) -> Self {
+        let k = match axis.normalize() {
+            Some(k) => k,
+            None => return Affine3::identity(),
+        };
+        let (s, c) = angle.sin_cos();
+        let t = 1.0 - c;
+        let [x, y, z] = k.to_array();
+        let linear = Matrix3::new([
+            [t * x * x + c, t * x * y - s * z, t * x * z + s * y],
+            [t * x * y + s * z, t * y * y + c, t * y * z - s * x],
+            [t * x * z - s * y, t * y * z + s * x, t * z * z + c],
+        ]);
+        Affine3::new(linear, Vector3::zero())
+    }
+
+    /// Linear part of the map
+    pub fn linear(&self) -> &Matrix3 {
+        &self.linear
+    }
+
+    /// Translation part of the map
+    pub fn translation_part(&self) -> Vector3 {
+        self.translation
+    }
+
+    /// Map applying `self` first and then `next`
+    pub fn then(&self, next: &Affine3) -> Affine3 {
+        *next * *self
+    }
+
+    /// Inverse map, `None` when the linear part is singular
+    pub fn inverse(&self) -> Option<Affine3> {
+        let inv = self.linear.inverse()?;
+        Some(Affine3::new(inv, -(inv * self.translation)))
+    }
+
+    /// Common stretch factor when the linear part is a rotation, reflection
+    /// and uniform scaling, `None` when it distorts some directions more than others
+    pub fn uniform_scale(&self) -> Option<f64> {
+        let columns = [0, 1, 2].map(|i| self.linear.column(i));
+        let s2 = columns[0].length_squared();
+        let tol = 1e-9 * s2;
+        let equal = columns
+            .iter()
+            .all(|c| (c.length_squared() - s2).abs() <= tol);
+        let orthogonal = [(0, 1), (0, 2), (1, 2)]
+            .iter()
+            .all(|&(i, j)| columns[i].dot(&columns[j]).abs() <= tol);
+        if equal && orthogonal {
+            Some(s2.sqrt())
+        } else {
+            None
+        }
+    }
+
+    /// Applies the map to a point
+    pub fn apply_point(&self, p: &Point) -> Point {
+        Point::from(self.linear * p.to_vector()) + self.translation
+    }
+
+    /// Applies the linear part of the map to a vector
+    pub fn apply_vector(&self, v: &Vector3) -> Vector3 {
+        self.linear * *v
+    }
+
+    /// Homogeneous 4x4 form of the map
+    pub fn to_matrix4(&self) -> Matrix4 {
+        let m = self.linear.rows();
+        let t = self.translation.to_array();
+        Matrix4::new(std::array::from_fn(|r| {
+            if r == 3 {
+                [0.0, 0.0, 0.0, 1.0]
+            } else {
+                [m[r][0], m[r][1], m[r][2], t[r]]
+            }
+        }))
+    }
+}
+
+impl Mul for Affine3 {
+    type Output = Affine3;
+
+    /// Composition, `(a * b)` applies `b` first
+    fn mul(self, rhs: Affine3) -> Affine3 {
+        Affine3::new(
+            self.linear * rhs.linear,
+            self.linear * rhs.translation + self.translation,
+        )
+    }
+}
+
+impl Mul<Point> for Affine3 {
+    type Output = Point;
+
+    fn mul(self, p: Point) -> Point {
+        self.apply_point(&p)
+    }
+}
+
+impl Mul<Vector3> for Affine3 {
+    type Output = Vector3;
+
+    fn mul(self, v: Vector3) -> Vector3 {
+        self.apply_vector(&v)
+    }
+}
+
+impl From<Affine3> for Matrix4 {
+    fn from(a: Affine3) -> Matrix4 {
+        a.to_matrix4()
+    }
+}
+
+impl TryFrom<Matrix4> for Affine3 {
+    type Error = Matrix4;
+
+    /// Succeeds when the bottom row is `[0, 0, 0, 1]`, returns the matrix otherwise
+    fn try_from(m: Matrix4) -> Result<Affine3, Matrix4> {
+        let r = m.rows();
+        if r[3] != [0.0, 0.0, 0.0, 1.0] {
+            return Err(m);
+        }
+        let linear = Matrix3::new(std::array::from_fn(|i| [r[i][0], r[i][1], r[i][2]]));
+        Ok(Affine3::new(
+            linear,
+            Vector3::new(r[0][3], r[1][3], r[2][3]),
+        ))
+    }
+}
+
+/// Transformable trait supplies affine transformation of shapes without invariants
+///
+/// Any map gives a valid result, even a singular one collapsing the shape.
+/// Shapes that a map can invalidate implement [`TryTransformable`] instead.
+pub trait Transformable {
+    /// Copy of the shape with every point mapped through `t`
+    fn transform(&self, t: &Affine3) -> Self;
+
+    /// Copy of the shape moved by `v`
+    fn translated(&self, v: Vector3) -> Self
+    where
+        Self: Sized,
+    {
+        self.transform(&Affine3::translation(v))
+    }
+
+    /// Copy of the shape rotated about `axis` through the origin
+    fn rotated(&self, axis: Vector3, angle: Angle) -> Self
+    where
+        Self: Sized,
+    {
+        self.transform(&Affine3::rotation(axis, angle))
+    }
+
+    /// Copy of the shape scaled uniformly about the origin
+    fn scaled(&self, s: f64) -> Self
+    where
+        Self: Sized,
+    {
+        self.transform(&Affine3::uniform_scaling(s))
+    }
+}
+
+/// TryTransformable trait supplies affine transformation of validated shapes
+///
+/// The result is revalidated, so a map that collapses the shape, such as a
+/// zero scaling, gives an error instead of an invalid shape. Every
+/// [`Transformable`] type implements it and never fails.
+pub trait TryTransformable: Sized {
+    /// Copy of the shape with every point mapped through `t`
+    fn try_transform(&self, t: &Affine3) -> Result<Self, GeometryError>;
+
+    /// Copy of the shape moved by `v`
+    fn try_translated(&self, v: Vector3) -> Result<Self, GeometryError> {
+        self.try_transform(&Affine3::translation(v))
+    }
+
+    /// Copy of the shape rotated about `axis` through the origin
+    fn try_rotated(&self, axis: Vector3, angle: Angle) -> Result<Self, GeometryError> {
+        self.try_transform(&Affine3::rotation(axis, angle))
+    }
+
+    /// Copy of the shape scaled uniformly about the origin
+    fn try_scaled(&self, s: f64) -> Result<Self, GeometryError> {
+        self.try_transform(&Affine3::uniform_scaling(s))
+    }
+}
+
+impl<S: Transformable> TryTransformable for S {
+    fn try_transform(&self, t: &Affine3) -> Result<Self, GeometryError> {
+        Ok(self.transform(t))
+    }
+}
+
+impl Transformable for Point {
+    fn transform(&self, t: &Affine3) -> Self {
+        t.apply_point(self)
+    }
+}
+
+impl Transformable for Point3 {
+    fn transform(&self, t: &Affine3) -> Self {
+        Point3::from(t.apply_point(&Point::from(*self)))
+    }
+}
+
+impl Transformable for Vector3 {
+    /// Vectors are displacements, so only the linear part applies
+    fn transform(&self, t: &Affine3) -> Self {
+        t.apply_vector(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: Point, b: Point) -> bool {
+        a.distance(&b) < 1e-12
+    }
+
+    #[test]
+    fn compose_and_invert_test() {
+        let p = Point::new(1.0, 0.0, 0.0);
+        let rotate = Affine3::rotation_z(Angle::from_degrees(90.0));
+        let shift = Affine3::translation(Vector3::new(0.0, 0.0, 2.0));
+        let both = rotate.then(&shift);
+        assert!(close(both * p, Point::new(0.0, 1.0, 2.0)));
+        assert!(close(both.inverse().unwrap() * (both * p), p));
+        assert!(close(both.to_matrix4().transform_point(&p), both * p));
+        assert_eq!(Affine3::try_from(both.to_matrix4()), Ok(both));
+        assert_eq!(Affine3::uniform_scaling(0.0).inverse(), None);
+        assert!(
+            (rotate
+                .then(&Affine3::uniform_scaling(3.0))
+                .uniform_scale()
+                .unwrap()
+                - 3.0)
+                .abs()
+                < 1e-12
+        );
+        assert_eq!(Affine3::scaling(1.0, 2.0, 1.0).uniform_scale(), None);
+    }
+
+    #[test]
+    fn transformable_test() {
+        let p = Point::new(1.0, 2.0, 3.0);
+        assert_eq!(
+            p.translated(Vector3::new(1.0, 1.0, 1.0)),
+            Point::new(2.0, 3.0, 4.0)
+        );
+        assert_eq!(p.scaled(2.0), Point::new(2.0, 4.0, 6.0));
+        assert_eq!(p.try_scaled(0.0), Ok(Point::origin()));
+        let v = Vector3::unit_x().transform(&Affine3::translation(Vector3::unit_y()));
+        assert_eq!(v, Vector3::unit_x());
+        assert!(close(
+            Point::from(Point3::from(p).rotated(Vector3::unit_y(), Angle::from_degrees(90.0))),
+            Point::new(3.0, 2.0, -1.0)
+        ));
+    }
+}
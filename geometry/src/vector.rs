@@ -0,0 +1,162 @@
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::scalar::{Field, Real, Scalar};
+
+/// Vector3 struct represents a displacement in three dimensional space
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Vector3<T = f64> {
+    x: T,
+    y: T,
+    z: T,
+}
+
+impl<T: Scalar> Vector3<T> {
+    /// Creates a vector from its three components
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Vector3 { x, y, z }
+    }
+
+    /// The zero vector
+    pub fn zero() -> Self {
+        Vector3::new(T::zero(), T::zero(), T::zero())
+    }
+
+    /// Unit vector along the x axis
+    pub fn unit_x() -> Self {
+        Vector3::new(T::one(), T::zero(), T::zero())
+    }
+
+    /// Unit vector along the y axis
+    pub fn unit_y() -> Self {
+        Vector3::new(T::zero(), T::one(), T::zero())
+    }
+
+    /// Unit vector along the z axis
+    pub fn unit_z() -> Self {
+        Vector3::new(T::zero(), T::zero(), T::one())
+    }
+
+    /// X component
+    pub fn x(&self) -> T {
+        self.x.clone()
+    }
+
+    /// Y component
+    pub fn y(&self) -> T {
+        self.y.clone()
+    }
+
+    /// Z component
+    pub fn z(&self) -> T {
+        self.z.clone()
+    }
+
+    /// Components as an `[x, y, z]` array
+    pub fn to_array(&self) -> [T; 3] {
+        [self.x(), self.y(), self.z()]
+    }
+
+    /// Converts every component to the nearest `f64`
+    pub fn to_f64(&self) -> Vector3 {
+        Vector3::new(self.x.to_f64(), self.y.to_f64(), self.z.to_f64())
+    }
+
+    /// Dot product
+    pub fn dot(&self, other: &Self) -> T {
+        self.x() * other.x() + self.y() * other.y() + self.z() * other.z()
+    }
+
+    /// Cross product, right handed
+    pub fn cross(&self, other: &Self) -> Self {
+        Vector3::new(
+            self.y() * other.z() - self.z() * other.y(),
+            self.z() * other.x() - self.x() * other.z(),
+            self.x() * other.y() - self.y() * other.x(),
+        )
+    }
+
+    /// Squared Euclidean length
+    pub fn length_squared(&self) -> T {
+        self.dot(self)
+    }
+}
+
+impl<T: Real> Vector3<T> {
+    /// Euclidean length
+    pub fn length(&self) -> T {
+        self.length_squared().sqrt()
+    }
+
+    /// Unit vector in the same direction, `None` for the zero vector
+    pub fn normalize(&self) -> Option<Self> {
+        let len = self.length();
+        if len > T::zero() && len.to_f64().is_finite() {
+            Some(self.clone() / len)
+        } else {
+            None
+        }
+    }
+}
+
+impl<T> From<[T; 3]> for Vector3<T> {
+    fn from(c: [T; 3]) -> Self {
+        let [x, y, z] = c;
+        Vector3 { x, y, z }
+    }
+}
+
+impl<T: Scalar> Add for Vector3<T> {
+    type Output = Vector3<T>;
+
+    fn add(self, other: Vector3<T>) -> Vector3<T> {
+        Vector3::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl<T: Scalar> Sub for Vector3<T> {
+    type Output = Vector3<T>;
+
+    fn sub(self, other: Vector3<T>) -> Vector3<T> {
+        Vector3::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl<T: Scalar> Mul<T> for Vector3<T> {
+    type Output = Vector3<T>;
+
+    fn mul(self, s: T) -> Vector3<T> {
+        Vector3::new(self.x * s.clone(), self.y * s.clone(), self.z * s)
+    }
+}
+
+impl<T: Field> Div<T> for Vector3<T> {
+    type Output = Vector3<T>;
+
+    fn div(self, s: T) -> Vector3<T> {
+        Vector3::new(self.x / s.clone(), self.y / s.clone(), self.z / s)
+    }
+}
+
+impl<T: Scalar> Neg for Vector3<T> {
+    type Output = Vector3<T>;
+
+    fn neg(self) -> Vector3<T> {
+        Vector3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vector_products_test() {
+        let x: Vector3 = Vector3::unit_x();
+        let y = Vector3::unit_y();
+        assert_eq!(x.cross(&y), Vector3::unit_z());
+        assert_eq!(x.dot(&y), 0.0);
+        assert_eq!(Vector3::new(3.0, 0.0, 4.0).length(), 5.0);
+        assert_eq!(Vector3::new(0.0, 0.0, 2.0).normalize(), Some(Vector3::unit_z()));
+        assert_eq!(Vector3::<f64>::zero().normalize(), None);
+    }
+}
@@ -1,19 +1,6 @@
-#[cfg(test)]
-pub mod triangles {
-
-    use geometry::{Type,Dimensional};
-
-    #[test]
-    fn validate_type_test() {
-        let t = Triangle{};
-        assert!(matches!(t.dimensions(), Type::D2));
-    }
-
-    struct Triangle {}
-
-    impl Dimensional for Triangle {
-        fn dimensions(&self) -> Type {
-            return Type::D2;
-        }
-    }
-}
+pub mod metrics;
+pub mod tessellate;
+pub mod triangles;
+pub use metrics::{AngleKind,SideKind};
+pub use tessellate::Tessellate;
+pub use triangles::Triangle;
@@ -0,0 +1,264 @@
+use std::cmp::Ordering;
+
+use geometry::{Angle, Field, Point, Real, Scalar, Vector3};
+
+use crate::triangles::Triangle;
+
+/// Relative tolerance used when classifying angles and sides
+const CLASSIFY_TOLERANCE: f64 = 1e-9;
+
+/// AngleKind enum list of classifications by largest interior angle
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AngleKind {
+    Acute,
+    Right,
+    Obtuse,
+}
+
+/// SideKind enum list of classifications by equal side lengths
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SideKind {
+    Scalene,
+    Isosceles,
+    Equilateral,
+}
+
+/// True when `l` and `r` agree within the classification tolerance
+fn nearly_equal<T: Scalar>(l: &T, r: &T) -> bool {
+    let diff = (l.clone() - r.clone()).to_f64().abs();
+    diff <= CLASSIFY_TOLERANCE * l.to_f64().abs().max(r.to_f64().abs())
+}
+
+impl<T: Scalar> Triangle<T> {
+    /// Unscaled normal `(B - A) x (C - A)`, its length is twice the area
+    pub fn normal(&self) -> Vector3<T> {
+        let [a, b, c] = self.vertices().clone();
+        (b - a.clone()).cross(&(c - a))
+    }
+
+    /// Squared side lengths in the order of [`Triangle::side_lengths`], exact for exact scalars
+    pub fn side_lengths_squared(&self) -> [T; 3] {
+        let [a, b, c] = self.vertices();
+        [
+            b.distance_squared(c),
+            c.distance_squared(a),
+            a.distance_squared(b),
+        ]
+    }
+
+    /// Classifies the triangle by its largest interior angle
+    ///
+    /// Compares the squared sides with the law of cosines, so no roots are taken.
+    pub fn angle_kind(&self) -> AngleKind {
+        let mut s = self.side_lengths_squared();
+        s.sort_by(|l, r| l.partial_cmp(r).unwrap_or(Ordering::Equal));
+        let [a, b, c] = s;
+        let legs = a + b;
+        if nearly_equal(&legs, &c) {
+            AngleKind::Right
+        } else if legs > c {
+            AngleKind::Acute
+        } else {
+            AngleKind::Obtuse
+        }
+    }
+
+    /// Classifies the triangle by how many of its sides are equal
+    pub fn side_kind(&self) -> SideKind {
+        let [a, b, c] = self.side_lengths_squared();
+        match (
+            nearly_equal(&a, &b),
+            nearly_equal(&b, &c),
+            nearly_equal(&c, &a),
+        ) {
+            (true, true, _) | (true, _, true) | (_, true, true) => SideKind::Equilateral,
+            (false, false, false) => SideKind::Scalene,
+            _ => SideKind::Isosceles,
+        }
+    }
+}
+
+impl<T: Field> Triangle<T> {
+    /// Intersection of the medians
+    pub fn centroid(&self) -> Point<T> {
+        let [a, b, c] = self.vertices().clone();
+        Point::from((a.to_vector() + b.to_vector() + c.to_vector()) / T::from_i32(3))
+    }
+
+    /// Center of the circle through all three vertices, exact for exact scalars
+    pub fn circumcenter(&self) -> Point<T> {
+        let [a, b, c] = self.vertices().clone();
+        let u = b - a.clone();
+        let v = c - a.clone();
+        let n = u.cross(&v);
+        let offset = (v.clone() * u.length_squared() - u * v.length_squared()).cross(&n);
+        a + offset / (T::from_i32(2) * n.length_squared())
+    }
+
+    /// Intersection of the altitudes, exact for exact scalars
+    pub fn orthocenter(&self) -> Point<T> {
+        let [a, b, c] = self.vertices().clone();
+        let o = self.circumcenter();
+        o.clone() + (a - o.clone()) + (b - o.clone()) + (c - o)
+    }
+}
+
+impl<T: Real> Triangle<T> {
+    /// Side lengths `[|BC|, |CA|, |AB|]`, each opposite the vertex of the same index
+    pub fn side_lengths(&self) -> [T; 3] {
+        self.side_lengths_squared().map(|s| s.sqrt())
+    }
+
+    /// Sum of the side lengths
+    pub fn perimeter(&self) -> T {
+        let [a, b, c] = self.side_lengths();
+        a + b + c
+    }
+
+    /// Area from the cross product of two edges
+    pub fn area(&self) -> T {
+        self.normal().length() / T::from_i32(2)
+    }
+
+    /// Area from the side lengths using Kahan's numerically stable form of Heron's formula
+    pub fn area_heron(&self) -> T {
+        let mut s = self.side_lengths();
+        s.sort_by(|l, r| r.partial_cmp(l).unwrap_or(Ordering::Equal));
+        let [a, b, c] = s;
+        let p = (a.clone() + (b.clone() + c.clone()))
+            * (c.clone() - (a.clone() - b.clone()))
+            * (c.clone() + (a.clone() - b.clone()))
+            * (a + (b - c));
+        p.max_of(T::zero()).sqrt() / T::from_i32(4)
+    }
+
+    /// Interior angles at vertices A, B and C
+    pub fn angles(&self) -> [Angle<T>; 3] {
+        let [a, b, c] = self.vertices().clone();
+        let angle = |p: &Point<T>, q: &Point<T>, r: &Point<T>| {
+            let u = q.clone() - p.clone();
+            let v = r.clone() - p.clone();
+            Angle::atan2(u.cross(&v).length(), u.dot(&v))
+        };
+        [angle(&a, &b, &c), angle(&b, &c, &a), angle(&c, &a, &b)]
+    }
+
+    /// Radius of the circumscribed circle
+    pub fn circumradius(&self) -> T {
+        let [a, b, c] = self.side_lengths();
+        a * b * c / (T::from_i32(4) * self.area())
+    }
+
+    /// Center of the inscribed circle
+    pub fn incenter(&self) -> Point<T> {
+        let [pa, pb, pc] = self.vertices().clone();
+        let [a, b, c] = self.side_lengths();
+        let weighted =
+            pa.to_vector() * a.clone() + pb.to_vector() * b.clone() + pc.to_vector() * c.clone();
+        Point::from(weighted / (a + b + c))
+    }
+
+    /// Radius of the inscribed circle
+    pub fn inradius(&self) -> T {
+        T::from_i32(2) * self.area() / self.perimeter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::FRAC_PI_2;
+
+    fn close(l: f64, r: f64) -> bool {
+        (l - r).abs() < 1e-12
+    }
+
+    #[test]
+    fn right_triangle_metrics_test() {
+        let t = Triangle::new(
+            Point::origin(),
+            Point::new(4.0, 0.0, 0.0),
+            Point::new(0.0, 3.0, 0.0),
+        )
+        .unwrap();
+        assert_eq!(t.side_lengths(), [5.0, 3.0, 4.0]);
+        assert_eq!(t.perimeter(), 12.0);
+        assert_eq!(t.area(), 6.0);
+        assert!(close(t.area_heron(), 6.0));
+        assert!(close(t.angles()[0].radians(), FRAC_PI_2));
+        assert_eq!(t.circumcenter(), Point::new(2.0, 1.5, 0.0));
+        assert_eq!(t.circumradius(), 2.5);
+        assert_eq!(t.incenter(), Point::new(1.0, 1.0, 0.0));
+        assert_eq!(t.inradius(), 1.0);
+        assert_eq!(t.orthocenter(), Point::origin());
+        assert_eq!(t.angle_kind(), AngleKind::Right);
+        assert_eq!(t.side_kind(), SideKind::Scalene);
+    }
+
+    #[test]
+    fn classify_test() {
+        let h = 3.0_f64.sqrt();
+        let t = Triangle::new(
+            Point::origin(),
+            Point::new(2.0, 0.0, 0.0),
+            Point::new(1.0, h, 0.0),
+        )
+        .unwrap();
+        assert_eq!(t.side_kind(), SideKind::Equilateral);
+        assert_eq!(t.angle_kind(), AngleKind::Acute);
+        assert!(t.centroid().distance(&t.circumcenter()) < 1e-12);
+
+        let t = Triangle::new(
+            Point::origin(),
+            Point::new(4.0, 0.0, 0.0),
+            Point::new(2.0, 0.5, 0.0),
+        )
+        .unwrap();
+        assert_eq!(t.side_kind(), SideKind::Isosceles);
+        assert_eq!(t.angle_kind(), AngleKind::Obtuse);
+    }
+
+    #[test]
+    fn certified_area_test() {
+        use geometry::Interval;
+
+        let points = [
+            Point::new(0.1, 0.0, 0.0),
+            Point::new(0.5, 0.1, 0.0),
+            Point::new(0.2, 0.7, 0.3),
+        ];
+        let [a, b, c] = points.map(|p| Point::<Interval>::from_f64(&p).unwrap());
+        let t = Triangle::new(a, b, c).unwrap();
+        let area = t.area();
+        let rounded = Triangle::new(points[0], points[1], points[2])
+            .unwrap()
+            .area();
+        assert!(area.lo() <= area.hi() && area.width() < 1e-15);
+        assert!((area.midpoint() - rounded).abs() <= area.width() + 1e-16);
+        assert!(t.area_heron().intersection(&area).is_some());
+        assert_eq!(t.angle_kind(), AngleKind::Acute);
+    }
+
+    #[test]
+    fn fixed_point_metrics_test() {
+        use geometry::Fixed;
+
+        let points = [
+            Point::new(0.0, 0.0, 0.0),
+            Point::new(3.0, 0.0, 0.0),
+            Point::new(0.0, 4.0, 0.0),
+        ];
+        let [a, b, c] = points.map(|p| Point::<Fixed>::from_f64(&p).unwrap());
+        let t = Triangle::new(a, b, c).unwrap();
+        assert_eq!(t.perimeter(), Fixed::from(12));
+        assert_eq!(t.area(), Fixed::from(6));
+        assert_eq!(t.angle_kind(), AngleKind::Right);
+        let angles = t.angles();
+        assert!((angles[0].radians().to_f64() - FRAC_PI_2).abs() < 1e-8);
+        // Q32.32 raw values of pi/2, atan(4/3) and atan(3/4), identical on every platform
+        assert_eq!(
+            angles.map(|a| a.radians().to_raw()),
+            [6_746_518_852, 3_982_702_635, 2_763_816_217]
+        );
+    }
+}
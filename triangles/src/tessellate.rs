@@ -0,0 +1,255 @@
+use std::f64::consts::{FRAC_PI_2, PI};
+
+use geometry::round::chord_segments;
+use geometry::{Capsule, Circle, Cylinder, Ellipse, GeometryError, Point, Point2, Sphere, Vector3};
+
+use crate::triangles::Triangle;
+
+/// Upper bound on the triangles of one mesh
+pub const MAX_TRIANGLES: usize = 1 << 22;
+
+/// Tessellate trait approximates a round shape with triangles
+///
+/// `tolerance` bounds the distance between each edge of the mesh and the true
+/// boundary. Planar shapes are filled in the `z = 0` plane facing `+z`, solids
+/// are closed surfaces with outward facing triangles. A tolerance needing more
+/// than [`MAX_TRIANGLES`] fails with [`GeometryError::LimitExceeded`].
+pub trait Tessellate {
+    /// Triangles approximating the shape within `tolerance`
+    fn tessellate(&self, tolerance: f64) -> Result<Vec<Triangle>, GeometryError>;
+}
+
+/// Segments for a full turn at `radius`, failing unless `tolerance` is positive
+fn segments(radius: f64, tolerance: f64) -> Result<usize, GeometryError> {
+    if !tolerance.is_finite() {
+        Err(GeometryError::NonFinite)
+    } else if tolerance <= 0.0 {
+        Err(GeometryError::Degenerate)
+    } else {
+        Ok(chord_segments(radius, tolerance))
+    }
+}
+
+/// Fans a closed planar outline around `center`
+fn fan(center: Point2, outline: &[Point2]) -> Result<Vec<Triangle>, GeometryError> {
+    let lift = |p: &Point2| Point::new(p.x(), p.y(), 0.0);
+    let c = lift(&center);
+    (0..outline.len())
+        .map(|i| {
+            let next = &outline[(i + 1) % outline.len()];
+            Triangle::new(c, lift(&outline[i]), lift(next))
+        })
+        .collect()
+}
+
+/// Frame with `w` along `axis` and `u`, `v` completing a right-handed basis
+fn frame(axis: Vector3) -> [Vector3; 3] {
+    let w = axis.normalize().unwrap_or_else(Vector3::unit_z);
+    let helper = if w.x().abs() < 0.9 {
+        Vector3::unit_x()
+    } else {
+        Vector3::unit_y()
+    };
+    let u = w.cross(&helper).normalize().unwrap_or_else(Vector3::unit_x);
+    let v = w.cross(&u);
+    [u, v, w]
+}
+
+/// Surface of revolution swept `n` times around the frame's `w` axis
+///
+/// `profile` lists `(height, radius)` pairs from bottom to top, a zero
+/// radius closes the surface with a fan.
+fn lathe(
+    origin: Point,
+    axis: Vector3,
+    profile: &[(f64, f64)],
+    n: usize,
+) -> Result<Vec<Triangle>, GeometryError> {
+    let [u, v, w] = frame(axis);
+    let point = |(h, r): (f64, f64), j: usize| {
+        let phi = 2.0 * PI * j as f64 / n as f64;
+        origin + w * h + (u * phi.cos() + v * phi.sin()) * r
+    };
+    let required = (profile.len() - 1).saturating_mul(n).saturating_mul(2);
+    if required > MAX_TRIANGLES {
+        return Err(GeometryError::LimitExceeded {
+            required,
+            limit: MAX_TRIANGLES,
+        });
+    }
+    let mut triangles = Vec::with_capacity(required);
+    for band in profile.windows(2) {
+        let (lower, upper) = (band[0], band[1]);
+        for j in 0..n {
+            if lower.1 > 0.0 {
+                triangles.push(Triangle::new(
+                    point(lower, j),
+                    point(lower, j + 1),
+                    point(upper, j + 1),
+                )?);
+            }
+            if upper.1 > 0.0 {
+                triangles.push(Triangle::new(
+                    point(lower, j),
+                    point(upper, j + 1),
+                    point(upper, j),
+                )?);
+            }
+        }
+    }
+    Ok(triangles)
+}
+
+/// Quarter turn of a hemisphere from `from` to `to` radians of latitude in `steps`
+fn arc(center: f64, radius: f64, from: f64, to: f64, steps: usize) -> Vec<(f64, f64)> {
+    (0..=steps)
+        .map(|i| {
+            let lat = from + (to - from) * i as f64 / steps as f64;
+            // Pin the poles so the fans close exactly
+            let r = if lat.abs() == FRAC_PI_2 {
+                0.0
+            } else {
+                radius * lat.cos()
+            };
+            (center + radius * lat.sin(), r)
+        })
+        .collect()
+}
+
+impl Tessellate for Circle {
+    fn tessellate(&self, tolerance: f64) -> Result<Vec<Triangle>, GeometryError> {
+        let n = segments(self.radius(), tolerance)?;
+        let outline: Vec<_> = (0..n)
+            .map(|i| self.point_at(geometry::Angle::full_turn() * (i as f64 / n as f64)))
+            .collect();
+        fan(self.center(), &outline)
+    }
+}
+
+impl Tessellate for Ellipse {
+    /// Steps evenly in the parametric angle, sized for the longer semi-axis
+    fn tessellate(&self, tolerance: f64) -> Result<Vec<Triangle>, GeometryError> {
+        let [a, b] = self.semi_axes();
+        let n = segments(a.max(b), tolerance)?;
+        let outline: Vec<_> = (0..n)
+            .map(|i| self.point_at(geometry::Angle::full_turn() * (i as f64 / n as f64)))
+            .collect();
+        fan(self.center(), &outline)
+    }
+}
+
+impl Tessellate for Sphere {
+    /// Halves the chord tolerance so the quad diagonals also stay within `tolerance`
+    fn tessellate(&self, tolerance: f64) -> Result<Vec<Triangle>, GeometryError> {
+        let n = segments(self.radius(), tolerance / 2.0)?;
+        let profile = arc(0.0, self.radius(), -FRAC_PI_2, FRAC_PI_2, n.div_ceil(2));
+        lathe(self.center(), Vector3::unit_z(), &profile, n)
+    }
+}
+
+impl Tessellate for Capsule {
+    /// Halves the chord tolerance so the quad diagonals also stay within `tolerance`
+    ///
+    /// A zero length axis gives a sphere, the caps then share their equator ring.
+    fn tessellate(&self, tolerance: f64) -> Result<Vec<Triangle>, GeometryError> {
+        let n = segments(self.radius(), tolerance / 2.0)?;
+        let axis = self.axis();
+        let steps = n.div_ceil(4);
+        let mut profile = arc(0.0, self.radius(), -FRAC_PI_2, 0.0, steps);
+        let upper = arc(axis.length(), self.radius(), 0.0, FRAC_PI_2, steps);
+        let shared = usize::from(axis.length() == 0.0);
+        profile.extend_from_slice(&upper[shared..]);
+        lathe(axis.start(), axis.direction(), &profile, n)
+    }
+}
+
+impl Tessellate for Cylinder {
+    fn tessellate(&self, tolerance: f64) -> Result<Vec<Triangle>, GeometryError> {
+        let n = segments(self.radius(), tolerance)?;
+        let (h, r) = (self.height(), self.radius());
+        let profile = [(0.0, 0.0), (0.0, r), (h, r), (h, 0.0)];
+        lathe(self.axis().start(), self.axis().direction(), &profile, n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Volume enclosed by a closed mesh, positive when the triangles face outward
+    fn signed_volume(mesh: &[Triangle]) -> f64 {
+        mesh.iter()
+            .map(|t| {
+                let [a, b, c] = t.vertices().map(|p| p - Point::origin());
+                a.dot(&b.cross(&c)) / 6.0
+            })
+            .sum()
+    }
+
+    #[test]
+    fn planar_test() {
+        let c = Circle::new(Point2::new(1.0, 2.0), 1.0).unwrap();
+        let mesh = c.tessellate(1.0 - (PI / 8.0).cos()).unwrap();
+        assert_eq!(mesh.len(), 8);
+        assert!(mesh.iter().all(|t| t.normal().z() > 0.0));
+        let area: f64 = mesh.iter().map(|t| t.area()).sum();
+        assert!((area - 2.0 * 2f64.sqrt()).abs() < 1e-12);
+        let e = Ellipse::new(Point2::origin(), 4.0, 1.0, geometry::Angle::zero()).unwrap();
+        let area: f64 = e.tessellate(1e-3).unwrap().iter().map(|t| t.area()).sum();
+        assert!(area < e.area() && e.area() - area < 1e-2);
+        assert_eq!(c.tessellate(0.0), Err(GeometryError::Degenerate));
+    }
+
+    #[test]
+    fn limit_test() {
+        let sphere = Sphere::new(Point::origin(), 1.0).unwrap();
+        assert!(matches!(
+            sphere.tessellate(1e-10),
+            Err(GeometryError::LimitExceeded {
+                limit: MAX_TRIANGLES,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn solid_test() {
+        let tolerance = 1e-3;
+        let sphere = Sphere::new(Point::new(1.0, 0.0, 0.0), 2.0).unwrap();
+        let capsule = Capsule::new(Point::origin(), Point::new(1.0, 2.0, 2.0), 0.5).unwrap();
+        let cylinder = Cylinder::new(Point::new(0.0, 0.0, 1.0), Point::origin(), 1.0).unwrap();
+        // An inscribed mesh loses a small multiple of `tolerance / radius` of the volume
+        for (mesh, volume, radius) in [
+            (
+                sphere.tessellate(tolerance),
+                sphere.volume(),
+                sphere.radius(),
+            ),
+            (
+                capsule.tessellate(tolerance),
+                capsule.volume(),
+                capsule.radius(),
+            ),
+            (
+                cylinder.tessellate(tolerance),
+                cylinder.volume(),
+                cylinder.radius(),
+            ),
+        ] {
+            let approx = signed_volume(&mesh.unwrap());
+            assert!(approx > 0.0 && approx <= volume);
+            assert!((volume - approx) / volume < 4.0 * tolerance / radius);
+        }
+        let ball = Capsule::new(Point::origin(), Point::origin(), 1.0).unwrap();
+        let approx = signed_volume(&ball.tessellate(1e-2).unwrap());
+        assert!(approx <= ball.volume() && ball.volume() - approx < 4e-2 * ball.volume());
+        let mesh = sphere.tessellate(tolerance).unwrap();
+        let depth = |p: Point| 2.0 - p.distance(&sphere.center());
+        assert!(mesh.iter().all(|t| {
+            let [a, b, c] = *t.vertices();
+            [a.lerp(&b, 0.5), b.lerp(&c, 0.5), c.lerp(&a, 0.5)]
+                .iter()
+                .all(|&m| (0.0..tolerance).contains(&depth(m)))
+        }));
+    }
+}
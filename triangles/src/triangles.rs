@@ -0,0 +1,382 @@
+use geometry::{
+    Affine3, ApproxEq, Dimensional, Field, GeometryError, Point, Reprojectable, Scalar,
+    TryTransformable, Type, D3,
+};
+
+/// Triangle struct represents a non-degenerate triangle given by three vertices
+///
+/// The coordinate type defaults to `f64`, any [`Scalar`] can be used instead.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Triangle<T = f64> {
+    vertices: [Point<T>; 3],
+}
+
+impl<T: Scalar> Triangle<T> {
+    /// Creates a triangle, rejecting non-finite, too large and collinear vertices
+    ///
+    /// Collinearity is decided exactly by [`Scalar::collinear`], so nearly
+    /// collinear vertices are accepted as long as they are not exactly collinear.
+    /// Integer coordinates must pass [`Scalar::fits_products`] so the metrics
+    /// cannot overflow.
+    pub fn new(a: Point<T>, b: Point<T>, c: Point<T>) -> Result<Self, GeometryError> {
+        if [&a, &b, &c].iter().flat_map(|p| p.to_array()).any(|v| !v.is_finite()) {
+            return Err(GeometryError::NonFinite);
+        }
+        if [&a, &b, &c].iter().flat_map(|p| p.to_array()).any(|v| !v.fits_products()) {
+            return Err(GeometryError::Overflow);
+        }
+        if T::collinear(&a, &b, &c) {
+            return Err(GeometryError::Degenerate);
+        }
+        Ok(Triangle {
+            vertices: [a, b, c],
+        })
+    }
+
+    /// The three vertices in construction order
+    pub fn vertices(&self) -> &[Point<T>; 3] {
+        &self.vertices
+    }
+
+    /// First vertex
+    pub fn a(&self) -> Point<T> {
+        self.vertices[0].clone()
+    }
+
+    /// Second vertex
+    pub fn b(&self) -> Point<T> {
+        self.vertices[1].clone()
+    }
+
+    /// Third vertex
+    pub fn c(&self) -> Point<T> {
+        self.vertices[2].clone()
+    }
+
+    /// Rounds every vertex to `f64`, failing if rounding makes the triangle degenerate
+    pub fn to_f64(&self) -> Result<Triangle, GeometryError> {
+        let [a, b, c] = &self.vertices;
+        Triangle::new(a.to_f64(), b.to_f64(), c.to_f64())
+    }
+
+    /// True when all vertices lie in a plane perpendicular to a coordinate axis
+    fn is_axis_planar(&self) -> bool {
+        let [a, b, c] = &self.vertices;
+        let [a, b, c] = [a.to_array(), b.to_array(), c.to_array()];
+        (0..3).any(|i| a[i] == b[i] && b[i] == c[i])
+    }
+}
+
+impl<T: Field> Triangle<T> {
+    /// Point where the segment `p`..`q` crosses the triangle, boundary included
+    ///
+    /// Returns `None` when the segment misses or lies in the triangle's plane;
+    /// the result is exact for exact scalars.
+    pub fn segment_intersection(&self, p: &Point<T>, q: &Point<T>) -> Option<Point<T>> {
+        let [a, b, c] = self.vertices().clone();
+        let n = self.normal();
+        let dp = n.dot(&(p.clone() - a.clone()));
+        let dq = n.dot(&(q.clone() - a.clone()));
+        let zero = T::zero();
+        if dp == dq || (dp > zero && dq > zero) || (dp < zero && dq < zero) {
+            return None;
+        }
+        let t = dp.clone() / (dp - dq);
+        let x = p.lerp(q, t);
+        let inside = |from: &Point<T>, to: &Point<T>| {
+            let edge = to.clone() - from.clone();
+            n.dot(&edge.cross(&(x.clone() - from.clone()))) >= T::zero()
+        };
+        if inside(&a, &b) && inside(&b, &c) && inside(&c, &a) {
+            Some(x)
+        } else {
+            None
+        }
+    }
+}
+
+impl<T: Scalar> Triangle<T>
+where
+    Point<T>: ApproxEq,
+{
+    /// True when `other` has approximately the same vertices in any order
+    ///
+    /// Uses the same tolerances as [`ApproxEq::relative_eq`].
+    pub fn relative_eq_unordered(&self, other: &Self, epsilon: f64, max_relative: f64) -> bool {
+        const PERMUTATIONS: [[usize; 3]; 6] = [
+            [0, 1, 2],
+            [1, 2, 0],
+            [2, 0, 1],
+            [0, 2, 1],
+            [2, 1, 0],
+            [1, 0, 2],
+        ];
+        PERMUTATIONS.iter().any(|perm| {
+            perm.iter().enumerate().all(|(i, &j)| {
+                self.vertices[i].relative_eq(&other.vertices[j], epsilon, max_relative)
+            })
+        })
+    }
+
+    /// Vertex-order-insensitive comparison with the default tolerances
+    pub fn approx_eq_unordered(&self, other: &Self) -> bool {
+        self.relative_eq_unordered(
+            other,
+            geometry::approx::DEFAULT_EPSILON,
+            geometry::approx::DEFAULT_MAX_RELATIVE,
+        )
+    }
+}
+
+impl<T: Field> Dimensional for Triangle<T> {
+    type Dim = D3;
+
+    /// D2 when the triangle lies in a plane perpendicular to a coordinate axis
+    fn dimensions(&self) -> Type {
+        if self.is_axis_planar() {
+            Type::D2
+        } else {
+            Type::D3
+        }
+    }
+
+    fn measure(&self) -> f64 {
+        self.normal().to_f64().length() / 2.0
+    }
+
+    fn bounding_box(&self) -> (Point, Point) {
+        let [a, b, c] = &self.vertices;
+        let [a, b, c] = [a.to_f64(), b.to_f64(), c.to_f64()];
+        (a.min(&b).min(&c), a.max(&b).max(&c))
+    }
+
+    fn centroid(&self) -> Point {
+        Triangle::centroid(self).to_f64()
+    }
+}
+
+impl<T: Scalar> ApproxEq for Triangle<T>
+where
+    Point<T>: ApproxEq,
+{
+    /// Compares vertices in construction order, see [`Triangle::relative_eq_unordered`]
+    fn abs_diff_eq(&self, other: &Triangle<T>, epsilon: f64) -> bool {
+        self.vertices.abs_diff_eq(&other.vertices, epsilon)
+    }
+
+    fn relative_eq(&self, other: &Triangle<T>, epsilon: f64, max_relative: f64) -> bool {
+        self.vertices
+            .relative_eq(&other.vertices, epsilon, max_relative)
+    }
+
+    fn ulps_eq(&self, other: &Triangle<T>, epsilon: f64, max_ulps: u32) -> bool {
+        self.vertices.ulps_eq(&other.vertices, epsilon, max_ulps)
+    }
+}
+
+impl TryTransformable for Triangle {
+    /// Maps each vertex and revalidates, failing if `t` collapses the triangle
+    fn try_transform(&self, t: &Affine3) -> Result<Self, GeometryError> {
+        let [a, b, c] = self.vertices.map(|p| t.apply_point(&p));
+        Triangle::new(a, b, c)
+    }
+}
+
+impl Reprojectable for Triangle {
+    /// Maps each vertex and revalidates, failing if the triangle collapses
+    fn map_points(
+        &self,
+        f: &mut dyn FnMut(&Point) -> Result<Point, GeometryError>,
+    ) -> Result<Self, GeometryError> {
+        let [a, b, c] = &self.vertices;
+        Triangle::new(f(a)?, f(b)?, f(c)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_type_test() {
+        let t = Triangle::new(
+            Point::new(0.0, 0.0, 0.0),
+            Point::new(1.0, 0.0, 0.0),
+            Point::new(0.0, 1.0, 0.0),
+        )
+        .unwrap();
+        assert!(matches!(t.dimensions(), Type::D2));
+
+        let t = Triangle::new(
+            Point::new(1.0, 0.0, 0.0),
+            Point::new(0.0, 1.0, 0.0),
+            Point::new(0.0, 0.0, 1.0),
+        )
+        .unwrap();
+        assert!(matches!(t.dimensions(), Type::D3));
+    }
+
+    #[test]
+    fn reject_invalid_test() {
+        let a = Point::origin();
+        let b = Point::new(1.0, 1.0, 1.0);
+        let c = Point::new(2.0, 2.0, 2.0);
+        assert_eq!(Triangle::new(a, b, c), Err(GeometryError::Degenerate));
+        assert_eq!(Triangle::new(a, a, b), Err(GeometryError::Degenerate));
+        let a = Point::new(0.1, 0.2, 0.3);
+        let b = Point::new(0.2, 0.4, 0.6);
+        assert_eq!(
+            Triangle::new(a, b, Point::new(0.4, 0.8, 1.2)),
+            Err(GeometryError::Degenerate)
+        );
+        assert!(Triangle::new(a, b, Point::new(0.3, 0.6, 0.9000000000000001)).is_ok());
+        let nan = Point::new(f64::NAN, 0.0, 0.0);
+        assert_eq!(Triangle::new(nan, b, c), Err(GeometryError::NonFinite));
+    }
+
+    #[test]
+    fn large_integer_test() {
+        let big = 600_000_000_i64;
+        let a = Point::new(-big, -big, 0);
+        let b = Point::new(big, big, 0);
+        let t = Triangle::new(a, b, Point::new(big, -big, 0)).unwrap();
+        assert_eq!(t.normal(), geometry::Vector3::new(0, 0, -4 * big * big));
+        assert_eq!(t.angle_kind(), crate::AngleKind::Right);
+        assert_eq!(t.side_kind(), crate::SideKind::Isosceles);
+        assert_eq!(
+            Triangle::new(a, b, Point::new(0, 0, 0)),
+            Err(GeometryError::Degenerate)
+        );
+        let huge = 4_000_000_000_i64;
+        assert_eq!(
+            Triangle::new(a, b, Point::new(huge, -huge, 0)),
+            Err(GeometryError::Overflow)
+        );
+        let small = Point::new(9_459, 0, 0);
+        assert!(Triangle::new(Point::origin(), small, Point::new(0, -9_459, 0)).is_ok());
+        assert_eq!(
+            Triangle::new(Point::origin(), small, Point::new(0, 9_460, 0)),
+            Err(GeometryError::Overflow)
+        );
+    }
+
+    #[test]
+    fn transform_test() {
+        let t = Triangle::new(
+            Point::origin(),
+            Point::new(1.0, 0.0, 0.0),
+            Point::new(0.0, 1.0, 0.0),
+        )
+        .unwrap();
+        let moved = t
+            .try_translated(geometry::Vector3::new(0.0, 0.0, 5.0))
+            .and_then(|t| t.try_scaled(2.0))
+            .unwrap();
+        assert_eq!(moved.c(), Point::new(0.0, 2.0, 10.0));
+        assert_eq!(moved.area(), 2.0);
+        assert_eq!(t.try_scaled(0.0), Err(GeometryError::Degenerate));
+        let flatten = Affine3::scaling(1.0, 0.0, 1.0);
+        assert_eq!(t.try_transform(&flatten), Err(GeometryError::Degenerate));
+    }
+
+    #[test]
+    fn approx_eq_test() {
+        let a = Point::origin();
+        let b = Point::new(1.0, 0.0, 0.0);
+        let c = Point::new(0.0, 1.0, 0.0);
+        let t = Triangle::new(a, b, c).unwrap();
+        let nudged = Triangle::new(a, b, Point::new(0.0, 1.0 + 1e-13, 0.0)).unwrap();
+        geometry::assert_approx_eq!(t, nudged);
+        let reordered = Triangle::new(c, a, b).unwrap();
+        assert!(!t.approx_eq(&reordered));
+        assert!(t.approx_eq_unordered(&reordered));
+        assert!(t.approx_eq_unordered(&Triangle::new(b, a, c).unwrap()));
+    }
+
+    #[test]
+    fn segment_intersection_test() {
+        let t = Triangle::new(
+            Point::origin(),
+            Point::new(2.0, 0.0, 0.0),
+            Point::new(0.0, 2.0, 0.0),
+        )
+        .unwrap();
+        let hit = t.segment_intersection(&Point::new(0.5, 0.5, -1.0), &Point::new(0.5, 0.5, 3.0));
+        assert_eq!(hit, Some(Point::new(0.5, 0.5, 0.0)));
+        let miss = t.segment_intersection(&Point::new(1.5, 1.5, -1.0), &Point::new(1.5, 1.5, 1.0));
+        assert_eq!(miss, None);
+        let short = t.segment_intersection(&Point::new(0.5, 0.5, 1.0), &Point::new(0.5, 0.5, 3.0));
+        assert_eq!(short, None);
+    }
+
+    #[cfg(feature = "exact")]
+    #[test]
+    fn exact_constructions_test() {
+        use geometry::Rational;
+
+        let exact =
+            |x: f64, y: f64, z: f64| Point::<Rational>::from_f64(&Point::new(x, y, z)).unwrap();
+        let t = Triangle::new(
+            exact(0.1, 0.0, 0.0),
+            exact(0.7, 0.1, 0.0),
+            exact(0.3, 0.9, 0.2),
+        )
+        .unwrap();
+        let o = t.circumcenter();
+        let [a, b, c] = t.vertices();
+        assert_eq!(o.distance_squared(a), o.distance_squared(b));
+        assert_eq!(o.distance_squared(b), o.distance_squared(c));
+        let x = t
+            .segment_intersection(&exact(0.3, 0.3, -1.0), &exact(0.3, 0.3, 1.0))
+            .unwrap();
+        assert_eq!(x.x(), Rational::from_f64(0.3).unwrap());
+        assert!(t.to_f64().unwrap().circumcenter().distance(&o.to_f64()) < 1e-12);
+        assert!(Triangle::new(
+            exact(0.1, 0.2, 0.3),
+            exact(0.2, 0.4, 0.6),
+            exact(0.4, 0.8, 1.2)
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn dyn_dimensional_test() {
+        let t = Triangle::new(
+            Point::new(0.0, 0.0, 1.0),
+            Point::new(3.0, 0.0, 1.0),
+            Point::new(0.0, 3.0, 2.0),
+        )
+        .unwrap();
+        let shapes: Vec<Box<dyn Dimensional<Dim = D3>>> =
+            vec![Box::new(t), Box::new(Point::new(-1.0, 5.0, 0.0))];
+        let (lo, hi) = shapes
+            .iter()
+            .map(|s| s.bounding_box())
+            .fold((t.a(), t.a()), |(lo, hi), (l, h)| (lo.min(&l), hi.max(&h)));
+        assert_eq!(lo, Point::new(-1.0, 0.0, 0.0));
+        assert_eq!(hi, Point::new(3.0, 5.0, 2.0));
+        assert_eq!(shapes[0].centroid(), Point::new(1.0, 1.0, 4.0 / 3.0));
+        assert_eq!(shapes[1].measure(), 0.0);
+        assert!((shapes[0].measure() - 90f64.sqrt() / 2.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn reproject_test() {
+        use geometry::{Crs, CrsRegistry, Tagged};
+
+        let t = Triangle::new(
+            Point::new(8.54, 47.37, 400.0),
+            Point::new(8.55, 47.37, 410.0),
+            Point::new(8.54, 47.38, 420.0),
+        )
+        .unwrap();
+        let registry = CrsRegistry::new();
+        let site = Tagged::new(Crs::WGS84, t);
+        let ecef = registry.reproject(&site, Crs::ECEF).unwrap();
+        assert!(ecef.value().a().distance(&ecef.value().b()) > 700.0);
+        let back = registry.reproject(&ecef, Crs::WGS84).unwrap();
+        assert!(back.value().abs_diff_eq(&t, 1e-9));
+        let line = Tagged::new(Crs::WGS84, vec![t.a(), t.b()]);
+        assert!(site.check_same(&line).is_ok());
+    }
+}